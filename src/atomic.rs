@@ -0,0 +1,247 @@
+use crate::UInt;
+use core::fmt::{Debug, Formatter};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::AtomicU16;
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicU8;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicUsize;
+use core::sync::atomic::Ordering;
+
+/// Maps a base data type (e.g. `u32`) to its atomic counterpart in `core::sync::atomic` (e.g. `AtomicU32`)
+pub trait AtomicStorage {
+    type Atomic;
+}
+
+/// An integer with an arbitrary number of bits that can be shared between threads. Internally, it
+/// uses the atomic type of its base data type, so an `AtomicUInt<u32, 20>` is an `AtomicU32`.
+///
+/// Arithmetic operations like `fetch_add` wrap at `BITS` (instead of the base data type), so a
+/// value can never be observed outside of the valid range.
+#[repr(transparent)]
+pub struct AtomicUInt<T: AtomicStorage, const BITS: usize> {
+    value: T::Atomic,
+}
+
+// fetch_update needs an ordering for the load, which can't be Release or AcqRel. This follows
+// what the standard library does for its own fetch_max and similar
+#[inline]
+const fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+macro_rules! atomic_uint_impl {
+    ($(($type:ident, $atomic:ident, $size:literal)),+) => {
+        $(
+            #[cfg(target_has_atomic = $size)]
+            impl AtomicStorage for $type {
+                type Atomic = $atomic;
+            }
+
+            #[cfg(target_has_atomic = $size)]
+            impl<const BITS: usize> AtomicUInt<$type, BITS> {
+                /// Creates a new atomic integer
+                #[inline]
+                pub const fn new(value: UInt<$type, BITS>) -> Self {
+                    Self { value: $atomic::new(value.value) }
+                }
+
+                /// Consumes the atomic and returns the contained value
+                #[inline]
+                pub fn into_inner(self) -> UInt<$type, BITS> {
+                    UInt { value: self.value.into_inner() }
+                }
+
+                /// Loads a value from the atomic integer
+                #[inline]
+                pub fn load(&self, order: Ordering) -> UInt<$type, BITS> {
+                    UInt { value: self.value.load(order) }
+                }
+
+                /// Stores a value into the atomic integer
+                #[inline]
+                pub fn store(&self, value: UInt<$type, BITS>, order: Ordering) {
+                    self.value.store(value.value, order)
+                }
+
+                /// Stores a value into the atomic integer, returning the previous value
+                #[inline]
+                pub fn swap(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    UInt { value: self.value.swap(value.value, order) }
+                }
+
+                /// Stores a value into the atomic integer if the current value is the same as the
+                /// `current` value. The return value is a result indicating whether the new value was
+                /// written and containing the previous value
+                #[inline]
+                pub fn compare_exchange(
+                    &self,
+                    current: UInt<$type, BITS>,
+                    new: UInt<$type, BITS>,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<UInt<$type, BITS>, UInt<$type, BITS>> {
+                    match self.value.compare_exchange(current.value, new.value, success, failure) {
+                        Ok(value) => Ok(UInt { value }),
+                        Err(value) => Err(UInt { value }),
+                    }
+                }
+
+                /// Like [compare_exchange](Self::compare_exchange), but is allowed to spuriously fail
+                /// even when the comparison succeeds, which can result in more efficient code on some
+                /// platforms
+                #[inline]
+                pub fn compare_exchange_weak(
+                    &self,
+                    current: UInt<$type, BITS>,
+                    new: UInt<$type, BITS>,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<UInt<$type, BITS>, UInt<$type, BITS>> {
+                    match self.value.compare_exchange_weak(current.value, new.value, success, failure) {
+                        Ok(value) => Ok(UInt { value }),
+                        Err(value) => Err(UInt { value }),
+                    }
+                }
+
+                /// Fetches the value, and applies a function to it that returns an optional new value.
+                /// Returns Ok(previous_value) if the function returned Some, else Err(previous_value)
+                #[inline]
+                pub fn fetch_update<F>(
+                    &self,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                    mut f: F,
+                ) -> Result<UInt<$type, BITS>, UInt<$type, BITS>>
+                where
+                    F: FnMut(UInt<$type, BITS>) -> Option<UInt<$type, BITS>>,
+                {
+                    match self.value.fetch_update(set_order, fetch_order, |value| {
+                        f(UInt { value }).map(|new| new.value)
+                    }) {
+                        Ok(value) => Ok(UInt { value }),
+                        Err(value) => Err(UInt { value }),
+                    }
+                }
+
+                /// Adds to the current value, wrapping around at the numeric bounds of this type
+                /// (not the base data type). Returns the previous value
+                #[inline]
+                pub fn fetch_add(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    if BITS == <$type>::BITS as usize {
+                        // The base data type wraps at the same point, so we can use it directly
+                        UInt { value: self.value.fetch_add(value.value, order) }
+                    } else {
+                        self.update(order, |current| current.wrapping_add(value))
+                    }
+                }
+
+                /// Subtracts from the current value, wrapping around at the numeric bounds of this type
+                /// (not the base data type). Returns the previous value
+                #[inline]
+                pub fn fetch_sub(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    if BITS == <$type>::BITS as usize {
+                        // The base data type wraps at the same point, so we can use it directly
+                        UInt { value: self.value.fetch_sub(value.value, order) }
+                    } else {
+                        self.update(order, |current| current.wrapping_sub(value))
+                    }
+                }
+
+                /// Adds to the current value, saturating at the maximum value. Returns the previous value
+                #[inline]
+                pub fn fetch_saturating_add(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    self.update(order, |current| current.saturating_add(value))
+                }
+
+                /// Subtracts from the current value, saturating at zero. Returns the previous value
+                #[inline]
+                pub fn fetch_saturating_sub(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    self.update(order, |current| current.saturating_sub(value))
+                }
+
+                /// Bitwise "and" with the current value. Returns the previous value
+                #[inline]
+                pub fn fetch_and(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    // Bit operations of two values that are within range stay within range
+                    UInt { value: self.value.fetch_and(value.value, order) }
+                }
+
+                /// Bitwise "or" with the current value. Returns the previous value
+                #[inline]
+                pub fn fetch_or(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    UInt { value: self.value.fetch_or(value.value, order) }
+                }
+
+                /// Bitwise "xor" with the current value. Returns the previous value
+                #[inline]
+                pub fn fetch_xor(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    UInt { value: self.value.fetch_xor(value.value, order) }
+                }
+
+                /// Maximum with the current value. Returns the previous value
+                #[inline]
+                pub fn fetch_max(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    UInt { value: self.value.fetch_max(value.value, order) }
+                }
+
+                /// Minimum with the current value. Returns the previous value
+                #[inline]
+                pub fn fetch_min(&self, value: UInt<$type, BITS>, order: Ordering) -> UInt<$type, BITS> {
+                    UInt { value: self.value.fetch_min(value.value, order) }
+                }
+
+                /// fetch_update for functions that always succeed
+                #[inline]
+                fn update<F>(&self, order: Ordering, mut f: F) -> UInt<$type, BITS>
+                where
+                    F: FnMut(UInt<$type, BITS>) -> UInt<$type, BITS>,
+                {
+                    match self.fetch_update(order, load_ordering(order), |current| Some(f(current))) {
+                        Ok(previous) => previous,
+                        Err(_) => unreachable!("update function never returns None"),
+                    }
+                }
+            }
+
+            #[cfg(target_has_atomic = $size)]
+            impl<const BITS: usize> Default for AtomicUInt<$type, BITS> {
+                #[inline]
+                fn default() -> Self {
+                    Self::new(UInt { value: 0 })
+                }
+            }
+
+            #[cfg(target_has_atomic = $size)]
+            impl<const BITS: usize> From<UInt<$type, BITS>> for AtomicUInt<$type, BITS> {
+                #[inline]
+                fn from(value: UInt<$type, BITS>) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            #[cfg(target_has_atomic = $size)]
+            impl<const BITS: usize> Debug for AtomicUInt<$type, BITS> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    Debug::fmt(&self.load(Ordering::Relaxed), f)
+                }
+            }
+        )+
+    };
+}
+
+atomic_uint_impl!(
+    (u8, AtomicU8, "8"),
+    (u16, AtomicU16, "16"),
+    (u32, AtomicU32, "32"),
+    (u64, AtomicU64, "64"),
+    (usize, AtomicUsize, "ptr")
+);
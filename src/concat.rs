@@ -0,0 +1,80 @@
+use crate::{Number, UInt};
+
+/// Checks that a UInt with BITS bits is made of a UInt with HI bits and a UInt with LO bits, so
+/// that width mismatches of `concat_*` and `split_*` fail to compile:
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::UInt;
+/// // 4 + 4 bits don't make up 12 bits
+/// let value = UInt::<u16, 12>::concat_u8(UInt::<u8, 4>::new(0xA), UInt::<u8, 4>::new(0xB));
+/// ```
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::UInt;
+/// let (hi, lo): (UInt<u8, 4>, UInt<u8, 4>) = UInt::<u16, 12>::new(0xABC).split_u8::<4, _>();
+/// ```
+struct Parts<const BITS: usize, const HI: usize, const LO: usize> {}
+
+impl<const BITS: usize, const HI: usize, const LO: usize> Parts<BITS, HI, LO> {
+    const VALID: () = {
+        assert!(HI > 0 && LO > 0, "parts need at least one bit");
+        assert!(HI + LO == BITS, "parts have to add up to BITS");
+    };
+}
+
+// Const fns can't convert between generic base data types on stable Rust, so there is one pair of
+// functions for every base data type of the parts, named after it (like from_u8 and widen_to_u8)
+macro_rules! concat_impl {
+    ([$($type:ident),+], $parts:tt) => {
+        $( concat_impl!($type, $parts); )+
+    };
+    ($type:ident, [$(($part:ident, $concat_fn:ident, $split_fn:ident)),+]) => {
+        impl<const BITS: usize> UInt<$type, BITS> {
+            $(
+                /// Concatenates two values, with `hi` becoming the most significant bits. The widths
+                /// of the parts have to add up to BITS, otherwise this fails to compile, e.g.
+                /// `UInt::<u16, 12>::concat_u8(hi, lo)` with hi being a `UInt<u8, 4>` and lo a
+                /// `UInt<u8, 8>`
+                #[inline]
+                pub const fn $concat_fn<const HI: usize, const LO: usize>(hi: UInt<$part, HI>, lo: UInt<$part, LO>) -> Self {
+                    let _ = Parts::<BITS, HI, LO>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    Self { value: ((hi.value as $type) << LO) | lo.value as $type }
+                }
+
+                /// Splits the value into its most significant HI bits and its least significant LO
+                /// bits, which is the inverse of the concat function with the same suffix. HI and LO
+                /// have to add up to BITS, otherwise this fails to compile.
+                ///
+                /// Ideally, HI would be `BITS - LO`, but a type that depends on it requires generic
+                /// const expressions, which aren't available on stable Rust. It can be inferred
+                /// instead: `let (hi, lo): (UInt<u8, 4>, UInt<u8, 8>) = value.split_u8::<8, _>();`
+                #[inline]
+                pub const fn $split_fn<const LO: usize, const HI: usize>(self) -> (UInt<$part, HI>, UInt<$part, LO>) {
+                    let _ = Parts::<BITS, HI, LO>::VALID;
+                    // Query MAX to ensure we get a compiler error if any definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    let _ = UInt::<$part, HI>::MAX;
+                    let _ = UInt::<$part, LO>::MAX;
+                    (
+                        UInt { value: (self.value >> LO) as $part },
+                        UInt { value: (self.value as $part) & UInt::<$part, LO>::MASK },
+                    )
+                }
+            )+
+        }
+    };
+}
+
+concat_impl!(
+    [u8, u16, u32, u64, u128, usize],
+    [
+        (u8, concat_u8, split_u8),
+        (u16, concat_u16, split_u16),
+        (u32, concat_u32, split_u32),
+        (u64, concat_u64, split_u64),
+        (u128, concat_u128, split_u128),
+        (usize, concat_usize, split_usize)
+    ]
+);
@@ -0,0 +1,408 @@
+//! Cyclic redundancy checks of any width, e.g. CRC-5/USB or CRC-15/CAN. The register and the
+//! result of a [Crc] are a `UInt` of the width of the CRC, so a CRC-15 is returned as a `u15`.
+//!
+//! A [Crc] is created from [CrcParams], which describe the algorithm in the same way as the
+//! catalogue of parametrised CRC algorithms by Greg Cook. A number of standard parameter sets are
+//! provided as constants, e.g. [CRC_15_CAN]. Data can be fed as bytes or as individual bits, which
+//! is needed by bus protocols whose frames aren't a multiple of 8 bits.
+
+use crate::aliases::{
+    u10, u11, u12, u13, u14, u15, u17, u21, u24, u3, u30, u31, u4, u40, u5, u6, u7, u82,
+};
+use crate::{Number, UInt, UnsignedNumber};
+
+/// The parameters of a CRC algorithm
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CrcParams<T> {
+    /// The generator polynomial without its leading x^BITS term, e.g. `0x4599` for CRC-15/CAN.
+    /// This is the normal (not reversed) representation, even for reflected algorithms
+    pub poly: T,
+
+    /// The initial value of the register
+    pub init: T,
+
+    /// Whether the bits of each byte are processed starting with the least significant bit
+    pub refin: bool,
+
+    /// Whether the final register value is reflected before xorout is applied
+    pub refout: bool,
+
+    /// The value that is xored with the final register value
+    pub xorout: T,
+
+    /// The CRC of the ASCII string "123456789"
+    pub check: T,
+}
+
+/// A CRC calculator for the given [CrcParams]. `T` is the UInt that holds the CRC, e.g. `u15` for a
+/// CRC-15.
+///
+/// `TABLE_SIZE` selects the implementation: `0` computes the CRC bit by bit without a table, `16`
+/// uses a table of 16 entries to process four bits at a time and `256` (the default) uses a table of
+/// 256 entries to process a byte at a time. The table is generated by [Crc::new], which is a const
+/// fn, so it can be computed at compile time:
+/// `const CAN: Crc<u15> = Crc::<u15>::new(CRC_15_CAN);`
+#[derive(Clone, Debug)]
+pub struct Crc<T: Number, const TABLE_SIZE: usize = 256> {
+    params: CrcParams<T>,
+    table: [T::UnderlyingType; TABLE_SIZE],
+}
+
+/// A CRC computation in progress, which allows feeding data in pieces. Created by [Crc::digest].
+#[derive(Clone, Debug)]
+pub struct Digest<'a, T: Number, const TABLE_SIZE: usize = 256> {
+    crc: &'a Crc<T, TABLE_SIZE>,
+    register: T::UnderlyingType,
+}
+
+// Internally, the register is kept in a working form that avoids shifting the input: For reflected
+// algorithms (refin), the register is reversed and occupies the lowest BITS bits. Otherwise, it is
+// moved to the top of the base data type. The register is converted to its regular form by
+// finalize_register.
+macro_rules! crc_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<const BITS: usize, const TABLE_SIZE: usize> Crc<UInt<$type, BITS>, TABLE_SIZE> {
+                const VALID: () = {
+                    assert!(
+                        TABLE_SIZE == 0 || TABLE_SIZE == 16 || TABLE_SIZE == 256,
+                        "TABLE_SIZE has to be 0 (bitwise), 16 or 256"
+                    );
+                };
+
+                // Number of bits that are processed by a table lookup
+                const CHUNK_BITS: usize = if TABLE_SIZE == 16 { 4 } else { 8 };
+
+                // Number of bits between the top of the CRC and the top of the base data type
+                const SHIFT: usize = <$type>::BITS as usize - BITS;
+
+                /// Creates a CRC calculator, generating its table if there is one
+                pub const fn new(params: CrcParams<UInt<$type, BITS>>) -> Self {
+                    let _ = Self::VALID;
+                    let _ = UInt::<$type, BITS>::MAX;
+                    let mut table = [0; TABLE_SIZE];
+                    let mut i = 0;
+                    while i < TABLE_SIZE {
+                        let register = if params.refin {
+                            i as $type
+                        } else {
+                            (i as $type) << (<$type>::BITS as usize - Self::CHUNK_BITS)
+                        };
+                        table[i] = Self::shift(&params, register, Self::CHUNK_BITS);
+                        i += 1;
+                    }
+                    Self { params, table }
+                }
+
+                /// Returns the parameters of the algorithm
+                #[inline]
+                pub const fn params(&self) -> &CrcParams<UInt<$type, BITS>> {
+                    &self.params
+                }
+
+                /// Computes the CRC of the given bytes
+                pub const fn checksum(&self, bytes: &[u8]) -> UInt<$type, BITS> {
+                    let mut register = self.init_register();
+                    let mut i = 0;
+                    while i < bytes.len() {
+                        register = self.update_byte(register, bytes[i]);
+                        i += 1;
+                    }
+                    self.finalize_register(register)
+                }
+
+                /// Starts a computation that can be fed in pieces
+                #[inline]
+                pub const fn digest(&self) -> Digest<'_, UInt<$type, BITS>, TABLE_SIZE> {
+                    Digest {
+                        crc: self,
+                        register: self.init_register(),
+                    }
+                }
+
+                // Shifts count bits out of the register, applying the polynomial as needed
+                const fn shift(params: &CrcParams<UInt<$type, BITS>>, register: $type, count: usize) -> $type {
+                    let mut register = register;
+                    let mut i = 0;
+                    if params.refin {
+                        let poly = params.poly.value.reverse_bits() >> Self::SHIFT;
+                        while i < count {
+                            register = if register & 1 != 0 {
+                                (register >> 1) ^ poly
+                            } else {
+                                register >> 1
+                            };
+                            i += 1;
+                        }
+                    } else {
+                        let poly = params.poly.value << Self::SHIFT;
+                        while i < count {
+                            register = if register >> (<$type>::BITS - 1) != 0 {
+                                (register << 1) ^ poly
+                            } else {
+                                register << 1
+                            };
+                            i += 1;
+                        }
+                    }
+                    register
+                }
+
+                #[inline]
+                const fn init_register(&self) -> $type {
+                    if self.params.refin {
+                        self.params.init.value.reverse_bits() >> Self::SHIFT
+                    } else {
+                        self.params.init.value << Self::SHIFT
+                    }
+                }
+
+                const fn update_byte(&self, register: $type, byte: u8) -> $type {
+                    if TABLE_SIZE == 0 {
+                        let register = if self.params.refin {
+                            register ^ byte as $type
+                        } else {
+                            register ^ ((byte as $type) << (<$type>::BITS - 8))
+                        };
+                        return Self::shift(&self.params, register, 8);
+                    }
+                    let mask = (TABLE_SIZE - 1) as $type;
+                    let mut register = register;
+                    let mut done = 0;
+                    while done < 8 {
+                        register = if self.params.refin {
+                            let chunk = (byte >> done) as $type;
+                            let index = ((register ^ chunk) & mask) as usize;
+                            match register.checked_shr(Self::CHUNK_BITS as u32) {
+                                Some(shifted) => shifted ^ self.table[index],
+                                None => self.table[index],
+                            }
+                        } else {
+                            let chunk = (byte >> (8 - Self::CHUNK_BITS - done)) as $type;
+                            let top = register >> (<$type>::BITS as usize - Self::CHUNK_BITS);
+                            let index = ((top ^ chunk) & mask) as usize;
+                            match register.checked_shl(Self::CHUNK_BITS as u32) {
+                                Some(shifted) => shifted ^ self.table[index],
+                                None => self.table[index],
+                            }
+                        };
+                        done += Self::CHUNK_BITS;
+                    }
+                    register
+                }
+
+                #[inline]
+                const fn update_bit(&self, register: $type, bit: bool) -> $type {
+                    let register = if !bit {
+                        register
+                    } else if self.params.refin {
+                        register ^ 1
+                    } else {
+                        register ^ (1 << (<$type>::BITS - 1))
+                    };
+                    Self::shift(&self.params, register, 1)
+                }
+
+                const fn finalize_register(&self, register: $type) -> UInt<$type, BITS> {
+                    let register = if self.params.refin {
+                        register
+                    } else {
+                        register >> Self::SHIFT
+                    };
+                    let register = if self.params.refin != self.params.refout {
+                        register.reverse_bits() >> Self::SHIFT
+                    } else {
+                        register
+                    };
+                    UInt {
+                        value: register ^ self.params.xorout.value,
+                    }
+                }
+            }
+
+            impl<'a, const BITS: usize, const TABLE_SIZE: usize> Digest<'a, UInt<$type, BITS>, TABLE_SIZE> {
+                /// Feeds the given bytes
+                pub fn update(&mut self, bytes: &[u8]) {
+                    for &byte in bytes {
+                        self.register = self.crc.update_byte(self.register, byte);
+                    }
+                }
+
+                /// Feeds a single bit
+                #[inline]
+                pub fn update_bit(&mut self, bit: bool) {
+                    self.register = self.crc.update_bit(self.register, bit);
+                }
+
+                /// Feeds the given bits in order
+                pub fn update_bits<I: IntoIterator<Item = bool>>(&mut self, bits: I) {
+                    for bit in bits {
+                        self.update_bit(bit);
+                    }
+                }
+
+                /// Feeds all bits of the given value (e.g. a `u11` identifier) in the order that
+                /// bytes are fed: Starting with the most significant bit or, if refin is set, the
+                /// least significant bit. Feeding a `u8` is the same as feeding it as a byte
+                pub fn update_value<N: UnsignedNumber>(&mut self, value: N) {
+                    for i in 0..N::BITS {
+                        let shift = if self.crc.params.refin { i } else { N::BITS - 1 - i };
+                        self.update_bit((value >> shift as u32) & N::ONE != N::ZERO);
+                    }
+                }
+
+                /// Returns the CRC of the data that has been fed so far
+                #[inline]
+                pub fn finalize(&self) -> UInt<$type, BITS> {
+                    self.crc.finalize_register(self.register)
+                }
+            }
+        )+
+    };
+}
+
+crc_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! crc_params {
+    ($(#[$attr:meta])* $name:ident: $t:ty, $poly:literal, $init:literal, $refin:literal, $refout:literal, $xorout:literal, $check:literal) => {
+        $(#[$attr])*
+        pub const $name: CrcParams<$t> = CrcParams {
+            poly: <$t>::new($poly),
+            init: <$t>::new($init),
+            refin: $refin,
+            refout: $refout,
+            xorout: <$t>::new($xorout),
+            check: <$t>::new($check),
+        };
+    };
+}
+
+type U8 = UInt<u8, 8>;
+type U16 = UInt<u16, 16>;
+type U32 = UInt<u32, 32>;
+type U64 = UInt<u64, 64>;
+
+crc_params!(
+    /// CRC-3/GSM
+    CRC_3_GSM: u3, 0x3, 0x0, false, false, 0x7, 0x4
+);
+crc_params!(
+    /// CRC-4/G-704, also known as CRC-4/ITU
+    CRC_4_G_704: u4, 0x3, 0x0, true, true, 0x0, 0x7
+);
+crc_params!(
+    /// CRC-5/EPC-C1G2, used by RFID tags
+    CRC_5_EPC_C1G2: u5, 0x09, 0x09, false, false, 0x00, 0x00
+);
+crc_params!(
+    /// CRC-5/G-704, also known as CRC-5/ITU
+    CRC_5_G_704: u5, 0x15, 0x00, true, true, 0x00, 0x07
+);
+crc_params!(
+    /// CRC-5/USB, used by USB token packets
+    CRC_5_USB: u5, 0x05, 0x1f, true, true, 0x1f, 0x19
+);
+crc_params!(
+    /// CRC-6/G-704, also known as CRC-6/ITU
+    CRC_6_G_704: u6, 0x03, 0x00, true, true, 0x00, 0x06
+);
+crc_params!(
+    /// CRC-7/MMC, used by SD and MMC cards
+    CRC_7_MMC: u7, 0x09, 0x00, false, false, 0x00, 0x75
+);
+crc_params!(
+    /// CRC-8/SMBUS
+    CRC_8_SMBUS: U8, 0x07, 0x00, false, false, 0x00, 0xf4
+);
+crc_params!(
+    /// CRC-8/AUTOSAR
+    CRC_8_AUTOSAR: U8, 0x2f, 0xff, false, false, 0xff, 0xdf
+);
+crc_params!(
+    /// CRC-10/ATM
+    CRC_10_ATM: u10, 0x233, 0x000, false, false, 0x000, 0x199
+);
+crc_params!(
+    /// CRC-11/FLEXRAY, used by the FlexRay header
+    CRC_11_FLEXRAY: u11, 0x385, 0x01a, false, false, 0x000, 0x5a3
+);
+crc_params!(
+    /// CRC-12/UMTS
+    CRC_12_UMTS: u12, 0x80f, 0x000, false, true, 0x000, 0xdaf
+);
+crc_params!(
+    /// CRC-13/BBC
+    CRC_13_BBC: u13, 0x1cf5, 0x0000, false, false, 0x0000, 0x04fa
+);
+crc_params!(
+    /// CRC-14/DARC
+    CRC_14_DARC: u14, 0x0805, 0x0000, true, true, 0x0000, 0x082d
+);
+crc_params!(
+    /// CRC-15/CAN, used by classical CAN frames
+    CRC_15_CAN: u15, 0x4599, 0x0000, false, false, 0x0000, 0x059e
+);
+crc_params!(
+    /// CRC-16/ARC
+    CRC_16_ARC: U16, 0x8005, 0x0000, true, true, 0x0000, 0xbb3d
+);
+crc_params!(
+    /// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE
+    CRC_16_IBM_3740: U16, 0x1021, 0xffff, false, false, 0x0000, 0x29b1
+);
+crc_params!(
+    /// CRC-16/KERMIT, also known as CRC-16/CCITT
+    CRC_16_KERMIT: U16, 0x1021, 0x0000, true, true, 0x0000, 0x2189
+);
+crc_params!(
+    /// CRC-16/MODBUS
+    CRC_16_MODBUS: U16, 0x8005, 0xffff, true, true, 0x0000, 0x4b37
+);
+crc_params!(
+    /// CRC-17/CAN-FD, used by CAN FD frames with up to 16 data bytes
+    CRC_17_CAN_FD: u17, 0x1685b, 0x00000, false, false, 0x00000, 0x04f03
+);
+crc_params!(
+    /// CRC-21/CAN-FD, used by CAN FD frames with more than 16 data bytes
+    CRC_21_CAN_FD: u21, 0x102899, 0x000000, false, false, 0x000000, 0x0ed841
+);
+crc_params!(
+    /// CRC-24/BLE, used by Bluetooth Low Energy
+    CRC_24_BLE: u24, 0x00065b, 0x555555, true, true, 0x000000, 0xc25a56
+);
+crc_params!(
+    /// CRC-24/OPENPGP
+    CRC_24_OPENPGP: u24, 0x864cfb, 0xb704ce, false, false, 0x000000, 0x21cf02
+);
+crc_params!(
+    /// CRC-30/CDMA
+    CRC_30_CDMA: u30, 0x2030b9c7, 0x3fffffff, false, false, 0x3fffffff, 0x04c34abf
+);
+crc_params!(
+    /// CRC-31/PHILIPS
+    CRC_31_PHILIPS: u31, 0x04c11db7, 0x7fffffff, false, false, 0x7fffffff, 0x0ce9e46c
+);
+crc_params!(
+    /// CRC-32/ISO-HDLC, the CRC-32 of Ethernet, zlib and PNG
+    CRC_32_ISO_HDLC: U32, 0x04c11db7, 0xffffffff, true, true, 0xffffffff, 0xcbf43926
+);
+crc_params!(
+    /// CRC-32/ISCSI, also known as CRC-32C
+    CRC_32_ISCSI: U32, 0x1edc6f41, 0xffffffff, true, true, 0xffffffff, 0xe3069283
+);
+crc_params!(
+    /// CRC-40/GSM
+    CRC_40_GSM: u40, 0x0004820009, 0x0000000000, false, false, 0xffffffffff, 0xd4164fc646
+);
+crc_params!(
+    /// CRC-64/ECMA-182
+    CRC_64_ECMA_182: U64, 0x42f0e1eba9ea3693, 0x0000000000000000, false, false, 0x0000000000000000, 0x6c40df5f0b497347
+);
+crc_params!(
+    /// CRC-64/XZ
+    CRC_64_XZ: U64, 0x42f0e1eba9ea3693, 0xffffffffffffffff, true, true, 0xffffffffffffffff, 0x995dc9bbdf1939fa
+);
+crc_params!(
+    /// CRC-82/DARC
+    CRC_82_DARC: u82, 0x0308c0111011401440411, 0x000000000000000000000, true, true, 0x000000000000000000000, 0x09ea83f625023801fd612
+);
@@ -0,0 +1,619 @@
+use crate::{Number, TryNewError, UInt};
+use core::cmp::Ordering;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// An unsigned integer whose number of bits (1 to 128) is only known at runtime, e.g. because it
+/// comes from a description file. Where possible, prefer `UInt`, which knows its number of bits at
+/// compile time and is therefore smaller and faster.
+///
+/// Operations on two values (like `+` or `checked_add`) require both to have the same number of
+/// bits and panic otherwise.
+///
+/// Like `UInt`, all formatting traits (including `Debug`) print only the value. The number of
+/// bits is available through [DynUInt::bits].
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DynUInt {
+    value: u128,
+    bits: usize,
+}
+
+impl DynUInt {
+    /// Returns the largest value that fits into the given number of bits
+    #[inline]
+    const fn mask(bits: usize) -> u128 {
+        assert!(bits >= 1 && bits <= 128, "DynUInt supports 1 to 128 bits");
+        u128::MAX >> (128 - bits)
+    }
+
+    #[inline]
+    const fn assert_same_bits(self, rhs: Self) {
+        assert!(
+            self.bits == rhs.bits,
+            "DynUInt operands have different numbers of bits"
+        );
+    }
+
+    /// Creates an instance. Panics if bits isn't within 1..=128 or if the value doesn't fit
+    #[inline]
+    pub const fn new(value: u128, bits: usize) -> Self {
+        assert!(value <= Self::mask(bits));
+
+        Self { value, bits }
+    }
+
+    /// Creates an instance or an error if the given value doesn't fit. Panics if bits isn't
+    /// within 1..=128
+    #[inline]
+    pub const fn try_new(value: u128, bits: usize) -> Result<Self, TryNewError> {
+        if value <= Self::mask(bits) {
+            Ok(Self { value, bits })
+        } else {
+            Err(TryNewError {})
+        }
+    }
+
+    /// Returns zero with the given number of bits
+    #[inline]
+    pub const fn min_value(bits: usize) -> Self {
+        let _ = Self::mask(bits);
+        Self { value: 0, bits }
+    }
+
+    /// Returns the largest value with the given number of bits
+    #[inline]
+    pub const fn max_value(bits: usize) -> Self {
+        Self {
+            value: Self::mask(bits),
+            bits,
+        }
+    }
+
+    /// Returns the type as a fundamental data type
+    #[inline]
+    pub const fn value(self) -> u128 {
+        self.value
+    }
+
+    /// Returns the number of bits
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.bits
+    }
+
+    /// Extracts bits from a given value. The extract is equivalent to: `new((value >> start_bit) & MASK, bits)`
+    /// Unlike new, extract doesn't perform range-checking so it is slightly more efficient.
+    /// panics if start_bit+bits doesn't fit within a u128
+    #[inline]
+    pub const fn extract(value: u128, start_bit: usize, bits: usize) -> Self {
+        let mask = Self::mask(bits);
+        assert!(start_bit + bits <= 128);
+
+        Self {
+            value: (value >> start_bit) & mask,
+            bits,
+        }
+    }
+
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value.wrapping_add(rhs.value) & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value.wrapping_sub(rhs.value) & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value.wrapping_mul(rhs.value) & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        Self {
+            // No need to mask here - divisions always produce a result that is <= self
+            value: self.value.wrapping_div(rhs.value),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn wrapping_shl(self, rhs: u32) -> Self {
+        let shift_amount = rhs % (self.bits as u32);
+        Self {
+            value: (self.value << shift_amount) & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn wrapping_shr(self, rhs: u32) -> Self {
+        let shift_amount = rhs % (self.bits as u32);
+        Self {
+            value: self.value >> shift_amount,
+            bits: self.bits,
+        }
+    }
+
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        let max = Self::mask(self.bits);
+        let value = match self.value.checked_add(rhs.value) {
+            Some(sum) if sum <= max => sum,
+            _ => max,
+        };
+        Self {
+            value,
+            bits: self.bits,
+        }
+    }
+
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value.saturating_sub(rhs.value),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        let max = Self::mask(self.bits);
+        let value = match self.value.checked_mul(rhs.value) {
+            Some(product) if product <= max => product,
+            _ => max,
+        };
+        Self {
+            value,
+            bits: self.bits,
+        }
+    }
+
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        self.assert_same_bits(rhs);
+        // When dividing unsigned numbers, we never need to saturate
+        Self {
+            value: self.value.saturating_div(rhs.value),
+            bits: self.bits,
+        }
+    }
+
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        let max = Self::mask(self.bits);
+        let value = match self.value.checked_pow(exp) {
+            Some(powed) if powed <= max => powed,
+            _ => max,
+        };
+        Self {
+            value,
+            bits: self.bits,
+        }
+    }
+
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.assert_same_bits(rhs);
+        match self.value.checked_add(rhs.value) {
+            Some(value) if value <= Self::mask(self.bits) => Some(Self {
+                value,
+                bits: self.bits,
+            }),
+            _ => None,
+        }
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.assert_same_bits(rhs);
+        match self.value.checked_sub(rhs.value) {
+            Some(value) => Some(Self {
+                value,
+                bits: self.bits,
+            }),
+            None => None,
+        }
+    }
+
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.assert_same_bits(rhs);
+        match self.value.checked_mul(rhs.value) {
+            Some(value) if value <= Self::mask(self.bits) => Some(Self {
+                value,
+                bits: self.bits,
+            }),
+            _ => None,
+        }
+    }
+
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.assert_same_bits(rhs);
+        match self.value.checked_div(rhs.value) {
+            Some(value) => Some(Self {
+                value,
+                bits: self.bits,
+            }),
+            None => None,
+        }
+    }
+
+    pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if rhs >= (self.bits as u32) {
+            None
+        } else {
+            Some(Self {
+                value: (self.value << rhs) & Self::mask(self.bits),
+                bits: self.bits,
+            })
+        }
+    }
+
+    pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if rhs >= (self.bits as u32) {
+            None
+        } else {
+            Some(Self {
+                value: self.value >> rhs,
+                bits: self.bits,
+            })
+        }
+    }
+
+    /// Reverses the order of bits in the integer. The least significant bit becomes the most significant bit, second least-significant bit becomes second most-significant bit, etc.
+    pub const fn reverse_bits(self) -> Self {
+        Self {
+            value: self.value.reverse_bits() >> (128 - self.bits),
+            bits: self.bits,
+        }
+    }
+
+    /// Returns the number of ones in the binary representation of self.
+    pub const fn count_ones(self) -> u32 {
+        self.value.count_ones()
+    }
+
+    /// Returns the number of zeros in the binary representation of self.
+    pub const fn count_zeros(self) -> u32 {
+        self.bits as u32 - self.value.count_ones()
+    }
+
+    /// Returns the number of leading zeros in the binary representation of self.
+    pub const fn leading_zeros(self) -> u32 {
+        self.value.leading_zeros() - (128 - self.bits as u32)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of self.
+    pub const fn trailing_zeros(self) -> u32 {
+        if self.value == 0 {
+            self.bits as u32
+        } else {
+            self.value.trailing_zeros()
+        }
+    }
+
+    /// Shifts the bits to the left by a specified amount, n, wrapping the truncated bits to the end of the resulting integer.
+    /// Please note this isn't the same operation as the << shifting operator!
+    pub const fn rotate_left(self, n: u32) -> Self {
+        let b = self.bits as u32;
+        let n = n % b;
+        if n == 0 {
+            return self;
+        }
+
+        let moved_bits = (self.value << n) & Self::mask(self.bits);
+        let truncated_bits = self.value >> (b - n);
+        Self {
+            value: moved_bits | truncated_bits,
+            bits: self.bits,
+        }
+    }
+
+    /// Shifts the bits to the right by a specified amount, n, wrapping the truncated bits to the beginning of the resulting integer.
+    /// Please note this isn't the same operation as the >> shifting operator!
+    pub const fn rotate_right(self, n: u32) -> Self {
+        let b = self.bits as u32;
+        let n = n % b;
+        if n == 0 {
+            return self;
+        }
+
+        let moved_bits = self.value >> n;
+        let truncated_bits = (self.value << (b - n)) & Self::mask(self.bits);
+        Self {
+            value: moved_bits | truncated_bits,
+            bits: self.bits,
+        }
+    }
+
+    /// Creates an instance from little endian bytes. The number of bits is 8 times the number of
+    /// bytes. Panics if there are no bytes or more than 16
+    pub const fn from_le_bytes(bytes: &[u8]) -> Self {
+        let bits = bytes.len() << 3;
+        let _ = Self::mask(bits);
+        let mut value = 0u128;
+        let mut i = bytes.len();
+        while i > 0 {
+            i -= 1;
+            value = (value << 8) | bytes[i] as u128;
+        }
+        Self { value, bits }
+    }
+
+    /// Creates an instance from big endian bytes. The number of bits is 8 times the number of
+    /// bytes. Panics if there are no bytes or more than 16
+    pub const fn from_be_bytes(bytes: &[u8]) -> Self {
+        let bits = bytes.len() << 3;
+        let _ = Self::mask(bits);
+        let mut value = 0u128;
+        let mut i = 0;
+        while i < bytes.len() {
+            value = (value << 8) | bytes[i] as u128;
+            i += 1;
+        }
+        Self { value, bits }
+    }
+
+    /// Writes the value as little endian bytes. Panics if the number of bits isn't a multiple of 8
+    /// or if the length of `bytes` doesn't match
+    pub fn write_le_bytes(self, bytes: &mut [u8]) {
+        assert!(self.bits & 7 == 0 && bytes.len() == self.bits >> 3);
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (self.value >> (i << 3)) as u8;
+        }
+    }
+
+    /// Writes the value as big endian bytes. Panics if the number of bits isn't a multiple of 8
+    /// or if the length of `bytes` doesn't match
+    pub fn write_be_bytes(self, bytes: &mut [u8]) {
+        assert!(self.bits & 7 == 0 && bytes.len() == self.bits >> 3);
+        for (i, byte) in bytes.iter_mut().rev().enumerate() {
+            *byte = (self.value >> (i << 3)) as u8;
+        }
+    }
+
+    /// Reverses the byte order of the integer. Panics if the number of bits isn't a multiple of 8
+    pub const fn swap_bytes(self) -> Self {
+        assert!(self.bits & 7 == 0);
+        Self {
+            value: self.value.swap_bytes() >> (128 - self.bits),
+            bits: self.bits,
+        }
+    }
+}
+
+// Values with a different number of bits can't be compared
+impl PartialOrd for DynUInt {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.bits == other.bits {
+            Some(self.value.cmp(&other.value))
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for DynUInt {
+    type Output = DynUInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        let sum = self.value + rhs.value;
+        let mask = Self::mask(self.bits);
+        #[cfg(debug_assertions)]
+        if (sum & !mask) != 0 {
+            panic!("attempt to add with overflow");
+        }
+        Self {
+            value: sum & mask,
+            bits: self.bits,
+        }
+    }
+}
+
+impl Sub for DynUInt {
+    type Output = DynUInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        // No need for extra overflow checking as the regular minus operator already handles it for us
+        Self {
+            value: (self.value - rhs.value) & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+}
+
+impl Mul for DynUInt {
+    type Output = DynUInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        let product = self.value * rhs.value;
+        let mask = Self::mask(self.bits);
+        #[cfg(debug_assertions)]
+        if (product & !mask) != 0 {
+            panic!("attempt to multiply with overflow");
+        }
+        Self {
+            value: product & mask,
+            bits: self.bits,
+        }
+    }
+}
+
+impl Div for DynUInt {
+    type Output = DynUInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        // Integer division can only make the value smaller. And as the result is same type as
+        // Self, there's no need to range-check or mask
+        Self {
+            value: self.value / rhs.value,
+            bits: self.bits,
+        }
+    }
+}
+
+impl BitAnd for DynUInt {
+    type Output = DynUInt;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value & rhs.value,
+            bits: self.bits,
+        }
+    }
+}
+
+impl BitOr for DynUInt {
+    type Output = DynUInt;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value | rhs.value,
+            bits: self.bits,
+        }
+    }
+}
+
+impl BitXor for DynUInt {
+    type Output = DynUInt;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.assert_same_bits(rhs);
+        Self {
+            value: self.value ^ rhs.value,
+            bits: self.bits,
+        }
+    }
+}
+
+impl Not for DynUInt {
+    type Output = DynUInt;
+
+    fn not(self) -> Self::Output {
+        Self {
+            value: !self.value & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+}
+
+impl Shl<u32> for DynUInt {
+    type Output = DynUInt;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        // With debug assertions, the << and >> operators throw an exception if the shift amount
+        // is larger than the number of bits (in which case the result would always be 0)
+        #[cfg(debug_assertions)]
+        if rhs as usize >= self.bits {
+            panic!("attempt to shift left with overflow")
+        }
+
+        Self {
+            value: (self.value << rhs) & Self::mask(self.bits),
+            bits: self.bits,
+        }
+    }
+}
+
+impl Shr<u32> for DynUInt {
+    type Output = DynUInt;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        #[cfg(debug_assertions)]
+        if rhs as usize >= self.bits {
+            panic!("attempt to shift right with overflow")
+        }
+
+        Self {
+            value: self.value >> rhs,
+            bits: self.bits,
+        }
+    }
+}
+
+macro_rules! dyn_uint_assign_impl {
+    ($(($trait:ident, $fn:ident, $op_trait:ident, $op_fn:ident, $rhs:ty)),+) => {
+        $(
+            impl $trait<$rhs> for DynUInt {
+                #[inline]
+                fn $fn(&mut self, rhs: $rhs) {
+                    *self = $op_trait::$op_fn(*self, rhs);
+                }
+            }
+        )+
+    };
+}
+
+dyn_uint_assign_impl!(
+    (AddAssign, add_assign, Add, add, DynUInt),
+    (SubAssign, sub_assign, Sub, sub, DynUInt),
+    (MulAssign, mul_assign, Mul, mul, DynUInt),
+    (DivAssign, div_assign, Div, div, DynUInt),
+    (BitAndAssign, bitand_assign, BitAnd, bitand, DynUInt),
+    (BitOrAssign, bitor_assign, BitOr, bitor, DynUInt),
+    (BitXorAssign, bitxor_assign, BitXor, bitxor, DynUInt),
+    (ShlAssign, shl_assign, Shl, shl, u32),
+    (ShrAssign, shr_assign, Shr, shr, u32)
+);
+
+macro_rules! dyn_uint_fmt_impl {
+    ($($trait:ident),+) => {
+        $(
+            impl $trait for DynUInt {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    $trait::fmt(&self.value, f)
+                }
+            }
+        )+
+    };
+}
+
+dyn_uint_fmt_impl!(Display, Debug, LowerHex, UpperHex, Octal, Binary);
+
+macro_rules! dyn_uint_conversion_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<const BITS: usize> From<UInt<$type, BITS>> for DynUInt {
+                #[inline]
+                fn from(value: UInt<$type, BITS>) -> Self {
+                    Self {
+                        value: value.value as u128,
+                        bits: BITS,
+                    }
+                }
+            }
+
+            /// Fails if the value doesn't fit into BITS, no matter the number of bits of the DynUInt
+            impl<const BITS: usize> TryFrom<DynUInt> for UInt<$type, BITS> {
+                type Error = TryNewError;
+
+                #[inline]
+                fn try_from(value: DynUInt) -> Result<Self, Self::Error> {
+                    if value.value <= Self::MAX.value as u128 {
+                        Ok(Self { value: value.value as $type })
+                    } else {
+                        Err(TryNewError {})
+                    }
+                }
+            }
+        )+
+    };
+}
+
+dyn_uint_conversion_impl!(u8, u16, u32, u64, u128, usize);
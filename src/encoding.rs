@@ -0,0 +1,117 @@
+use crate::{CompileTimeAssert, InvalidEncodingError, TryNewError, UInt};
+
+macro_rules! encoding_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<const BITS: usize> UInt<$type, BITS> {
+                /// Converts from binary to Gray code, in which consecutive values differ in a single
+                /// bit
+                #[inline]
+                pub const fn to_gray(self) -> Self {
+                    Self { value: self.value ^ (self.value >> 1) }
+                }
+
+                /// Converts from Gray code to binary
+                #[inline]
+                pub const fn from_gray(self) -> Self {
+                    let mut value = self.value;
+                    let mut shift = 1;
+                    while shift < BITS {
+                        value ^= value >> shift;
+                        shift <<= 1;
+                    }
+                    Self { value }
+                }
+
+                /// Encodes the value as packed BCD, with one decimal digit per nibble (e.g. 59 becomes
+                /// 0x59). Returns an error if the digits don't fit within BITS
+                pub const fn to_bcd(self) -> Result<Self, TryNewError> {
+                    let mut value = self.value;
+                    let mut result: $type = 0;
+                    let mut shift = 0;
+                    while value != 0 {
+                        if shift >= BITS {
+                            return Err(TryNewError {});
+                        }
+                        result |= (value % 10) << shift;
+                        value /= 10;
+                        shift += 4;
+                    }
+                    if result > Self::MASK {
+                        return Err(TryNewError {});
+                    }
+                    Ok(Self { value: result })
+                }
+
+                /// Decodes packed BCD, with one decimal digit per nibble (e.g. 0x59 becomes 59).
+                /// Returns an error if a nibble is larger than 9
+                pub const fn from_bcd(self) -> Result<Self, InvalidEncodingError> {
+                    let mut remaining = self.value;
+                    let mut result: $type = 0;
+                    let mut factor: $type = 1;
+                    while remaining != 0 {
+                        let digit = remaining & 0xF;
+                        if digit > 9 {
+                            return Err(InvalidEncodingError {});
+                        }
+                        // The decimal value is never larger than the BCD value, so this can't overflow
+                        result += digit * factor;
+                        remaining >>= 4;
+                        if remaining != 0 {
+                            factor *= 10;
+                        }
+                    }
+                    Ok(Self { value: result })
+                }
+
+                /// Returns the one-hot encoding of index, i.e. only bit `index` is set. Fails to
+                /// compile if BITS is smaller than `2^INDEX_BITS`
+                #[inline]
+                pub const fn one_hot<const INDEX_BITS: usize>(index: UInt<u8, INDEX_BITS>) -> Self {
+                    let _ = CompileTimeAssert::<INDEX_BITS, BITS>::POWER_OF_TWO_SMALLER_OR_EQUAL;
+                    Self { value: 1 << index.value }
+                }
+
+                /// Decodes a one-hot encoding, returning the index of the set bit. Returns an error
+                /// if not exactly one bit is set or if the index doesn't fit into `INDEX_BITS`
+                #[inline]
+                pub const fn decode_one_hot<const INDEX_BITS: usize>(self) -> Result<UInt<u8, INDEX_BITS>, InvalidEncodingError> {
+                    let _ = CompileTimeAssert::<INDEX_BITS, BITS>::POWER_OF_TWO_SMALLER_OR_EQUAL;
+                    if !self.value.is_power_of_two() {
+                        return Err(InvalidEncodingError {});
+                    }
+                    match UInt::<u8, INDEX_BITS>::try_new(self.value.trailing_zeros() as u8) {
+                        Ok(index) => Ok(index),
+                        Err(_) => Err(InvalidEncodingError {}),
+                    }
+                }
+
+                /// Returns the thermometer encoding of count, i.e. the lowest `count` bits are set.
+                /// Fails to compile if BITS is smaller than `2^INDEX_BITS`
+                #[inline]
+                pub const fn thermometer<const INDEX_BITS: usize>(count: UInt<u8, INDEX_BITS>) -> Self {
+                    let _ = CompileTimeAssert::<INDEX_BITS, BITS>::POWER_OF_TWO_SMALLER_OR_EQUAL;
+                    // count is at most BITS - 1, so the shift can't overflow
+                    Self { value: (1 << count.value) - 1 }
+                }
+
+                /// Decodes a thermometer encoding, returning the number of set bits. Returns an error
+                /// if the set bits aren't contiguous starting at bit 0 or if the count doesn't fit into
+                /// `INDEX_BITS`
+                #[inline]
+                pub const fn decode_thermometer<const INDEX_BITS: usize>(self) -> Result<UInt<u8, INDEX_BITS>, InvalidEncodingError> {
+                    let _ = CompileTimeAssert::<INDEX_BITS, BITS>::POWER_OF_TWO_SMALLER_OR_EQUAL;
+                    if self.value & self.value.wrapping_add(1) != 0 {
+                        return Err(InvalidEncodingError {});
+                    }
+                    match UInt::<u8, INDEX_BITS>::try_new(self.value.count_ones() as u8) {
+                        Ok(count) => Ok(count),
+                        Err(_) => Err(InvalidEncodingError {}),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+encoding_impl!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,606 @@
+use crate::{CompileTimeAssert, Number, TryNewError, UInt};
+use core::fmt::{Debug, Display, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// An unsigned fixed-point number in Q format, e.g. `UFixed<u10, 2, 8>` for UQ2.8 or
+/// `UFixed<UInt<u16, 16>, 4, 12>` for UQ4.12. The raw value is stored in the UInt `T`, whose number of bits
+/// must equal `INT_BITS + FRAC_BITS`. The value is `raw / 2^FRAC_BITS`.
+///
+/// Multiplications and divisions round to the nearest representable value (with ties rounding up).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[repr(transparent)]
+pub struct UFixed<T, const INT_BITS: usize, const FRAC_BITS: usize> {
+    raw: T,
+}
+
+struct FormatAssert<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize>;
+
+impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize>
+    FormatAssert<BITS, INT_BITS, FRAC_BITS>
+{
+    const VALID: () = {
+        assert!(
+            INT_BITS + FRAC_BITS == BITS,
+            "INT_BITS + FRAC_BITS must equal the number of bits of the UInt"
+        );
+    };
+}
+
+// The shift operators panic for shifts >= 128 (which FRAC_BITS can reach), so use these instead
+
+#[inline]
+const fn shl(value: u128, shift: usize) -> u128 {
+    if shift >= 128 {
+        0
+    } else {
+        value << shift
+    }
+}
+
+#[inline]
+const fn shr(value: u128, shift: usize) -> u128 {
+    if shift >= 128 {
+        0
+    } else {
+        value >> shift
+    }
+}
+
+// 256 bit helpers for multiplication and division. Values are (low, high)
+
+const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a_low, a_high) = (a as u64 as u128, a >> 64);
+    let (b_low, b_high) = (b as u64 as u128, b >> 64);
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+    let middle = (low_low >> 64) + (low_high as u64 as u128) + (high_low as u64 as u128);
+    let low = (low_low as u64 as u128) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (low, high)
+}
+
+const fn shr_wide(value: (u128, u128), shift: usize) -> (u128, u128) {
+    let (low, high) = value;
+    if shift == 0 {
+        (low, high)
+    } else if shift < 128 {
+        ((low >> shift) | (high << (128 - shift)), high >> shift)
+    } else {
+        (shr(high, shift - 128), 0)
+    }
+}
+
+/// Returns (a * b) / 2^frac_bits, rounded to the nearest integer
+const fn mul_rounded(a: u128, b: u128, frac_bits: usize) -> (u128, u128) {
+    let (mut low, mut high) = widening_mul(a, b);
+    if frac_bits > 0 {
+        // This can't overflow, as the product is at most (2^128 - 1)^2
+        let (sum, carry) = low.overflowing_add(1 << (frac_bits - 1));
+        low = sum;
+        high += carry as u128;
+    }
+    shr_wide((low, high), frac_bits)
+}
+
+/// Returns (a * 2^frac_bits) / b, rounded to the nearest integer. b must not be zero
+const fn div_rounded(a: u128, b: u128, frac_bits: usize) -> (u128, u128) {
+    let numerator_low = shl(a, frac_bits);
+    let numerator_high = if frac_bits == 0 {
+        0
+    } else {
+        shr(a, 128 - frac_bits)
+    };
+    let (mut quotient_low, mut quotient_high, remainder) = if numerator_high == 0 {
+        (numerator_low / b, 0, numerator_low % b)
+    } else {
+        // Binary long division
+        let mut quotient_low = 0u128;
+        let mut quotient_high = 0u128;
+        let mut remainder = 0u128;
+        let mut i = 256;
+        while i > 0 {
+            i -= 1;
+            let bit = if i >= 128 {
+                (numerator_high >> (i - 128)) & 1
+            } else {
+                (numerator_low >> i) & 1
+            };
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | bit;
+            if carry == 1 || remainder >= b {
+                remainder = remainder.wrapping_sub(b);
+                if i >= 128 {
+                    quotient_high |= 1 << (i - 128);
+                } else {
+                    quotient_low |= 1 << i;
+                }
+            }
+        }
+        (quotient_low, quotient_high, remainder)
+    };
+    // Round up if remainder / b >= 0.5
+    if remainder >= b - remainder {
+        let (sum, carry) = quotient_low.overflowing_add(1);
+        quotient_low = sum;
+        quotient_high += carry as u128;
+    }
+    (quotient_low, quotient_high)
+}
+
+/// Returns 2^exponent for exponents in -1022..=1023
+fn pow2_f64(exponent: i32) -> f64 {
+    f64::from_bits(((1023 + exponent) as u64) << 52)
+}
+
+/// Returns 2^exponent for exponents in -126..=127
+fn pow2_f32(exponent: i32) -> f32 {
+    f32::from_bits(((127 + exponent) as u32) << 23)
+}
+
+macro_rules! ufixed_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                const RAW_MAX: u128 = {
+                    let _ = FormatAssert::<BITS, INT_BITS, FRAC_BITS>::VALID;
+                    <UInt<$type, BITS> as Number>::MAX.value as u128
+                };
+
+                /// The smallest value, zero
+                pub const MIN: Self = Self::from_raw(0);
+
+                /// The largest value
+                pub const MAX: Self = Self::from_raw(Self::RAW_MAX);
+
+                /// The difference between two neighboring values, `2^-FRAC_BITS`
+                pub const DELTA: Self = Self::from_raw(1);
+
+                #[inline]
+                const fn from_raw(raw: u128) -> Self {
+                    Self {
+                        raw: UInt { value: raw as $type },
+                    }
+                }
+
+                #[inline]
+                const fn raw(self) -> u128 {
+                    self.raw.value as u128
+                }
+
+                /// Creates a fixed-point number from its raw representation
+                #[inline]
+                pub const fn from_bits(raw: UInt<$type, BITS>) -> Self {
+                    let _ = FormatAssert::<BITS, INT_BITS, FRAC_BITS>::VALID;
+                    Self { raw }
+                }
+
+                /// Returns the raw representation, which is the value multiplied by `2^FRAC_BITS`
+                #[inline]
+                pub const fn to_bits(self) -> UInt<$type, BITS> {
+                    self.raw
+                }
+
+                /// Creates a fixed-point number from an integer. Panics if it doesn't fit into INT_BITS
+                #[inline]
+                pub const fn from_int(value: $type) -> Self {
+                    match Self::try_from_int(value) {
+                        Ok(result) => result,
+                        Err(_) => panic!("value is too large for the integer bits"),
+                    }
+                }
+
+                /// Creates a fixed-point number from an integer or an error if it doesn't fit into INT_BITS
+                #[inline]
+                pub const fn try_from_int(value: $type) -> Result<Self, TryNewError> {
+                    let _ = Self::RAW_MAX;
+                    if shr(value as u128, INT_BITS) == 0 {
+                        Ok(Self::from_raw(shl(value as u128, FRAC_BITS)))
+                    } else {
+                        Err(TryNewError {})
+                    }
+                }
+
+                /// Returns the integer part, rounding down
+                #[inline]
+                pub const fn int_part(self) -> $type {
+                    shr(self.raw(), FRAC_BITS) as $type
+                }
+
+                /// Returns the fractional part, i.e. the value without the integer part
+                #[inline]
+                pub const fn frac_part(self) -> Self {
+                    Self::from_raw(self.raw() & !shl(u128::MAX, FRAC_BITS))
+                }
+
+                /// Returns a fixed-point number with the same base data type and at least as many
+                /// integer and fractional bits. This conversion is lossless
+                pub const fn widen<const BITS_RESULT: usize, const INT_BITS_RESULT: usize, const FRAC_BITS_RESULT: usize>(
+                    self,
+                ) -> UFixed<UInt<$type, BITS_RESULT>, INT_BITS_RESULT, FRAC_BITS_RESULT> {
+                    let _ = CompileTimeAssert::<INT_BITS, INT_BITS_RESULT>::SMALLER_OR_EQUAL;
+                    let _ = CompileTimeAssert::<FRAC_BITS, FRAC_BITS_RESULT>::SMALLER_OR_EQUAL;
+                    let _ = UFixed::<UInt<$type, BITS_RESULT>, INT_BITS_RESULT, FRAC_BITS_RESULT>::RAW_MAX;
+                    UFixed::<UInt<$type, BITS_RESULT>, INT_BITS_RESULT, FRAC_BITS_RESULT>::from_raw(shl(
+                        self.raw(),
+                        FRAC_BITS_RESULT - FRAC_BITS,
+                    ))
+                }
+
+                /// Adds. Returns `None` on overflow
+                pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+                    match self.raw.checked_add(rhs.raw) {
+                        Some(raw) => Some(Self { raw }),
+                        None => None,
+                    }
+                }
+
+                /// Subtracts. Returns `None` if the result would be negative
+                pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    match self.raw.checked_sub(rhs.raw) {
+                        Some(raw) => Some(Self { raw }),
+                        None => None,
+                    }
+                }
+
+                /// Multiplies, rounding to the nearest value. Returns `None` on overflow
+                pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    let (low, high) = mul_rounded(self.raw(), rhs.raw(), FRAC_BITS);
+                    if high == 0 && low <= Self::RAW_MAX {
+                        Some(Self::from_raw(low))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Divides, rounding to the nearest value. Returns `None` on overflow or when dividing by zero
+                pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                    if rhs.raw() == 0 {
+                        return None;
+                    }
+                    let (low, high) = div_rounded(self.raw(), rhs.raw(), FRAC_BITS);
+                    if high == 0 && low <= Self::RAW_MAX {
+                        Some(Self::from_raw(low))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Adds. On overflow, the upper bits are discarded
+                pub const fn wrapping_add(self, rhs: Self) -> Self {
+                    Self {
+                        raw: self.raw.wrapping_add(rhs.raw),
+                    }
+                }
+
+                /// Subtracts. If the result would be negative, it wraps around at the upper bound
+                pub const fn wrapping_sub(self, rhs: Self) -> Self {
+                    Self {
+                        raw: self.raw.wrapping_sub(rhs.raw),
+                    }
+                }
+
+                /// Multiplies, rounding to the nearest value. On overflow, the upper bits are discarded
+                pub const fn wrapping_mul(self, rhs: Self) -> Self {
+                    let (low, _) = mul_rounded(self.raw(), rhs.raw(), FRAC_BITS);
+                    Self::from_raw(low & Self::RAW_MAX)
+                }
+
+                /// Divides, rounding to the nearest value. On overflow, the upper bits are discarded.
+                /// Panics when dividing by zero
+                pub const fn wrapping_div(self, rhs: Self) -> Self {
+                    assert!(rhs.raw() != 0, "attempt to divide by zero");
+                    let (low, _) = div_rounded(self.raw(), rhs.raw(), FRAC_BITS);
+                    Self::from_raw(low & Self::RAW_MAX)
+                }
+
+                /// Adds, saturating at MAX
+                pub const fn saturating_add(self, rhs: Self) -> Self {
+                    Self {
+                        raw: self.raw.saturating_add(rhs.raw),
+                    }
+                }
+
+                /// Subtracts, saturating at zero
+                pub const fn saturating_sub(self, rhs: Self) -> Self {
+                    Self {
+                        raw: self.raw.saturating_sub(rhs.raw),
+                    }
+                }
+
+                /// Multiplies, rounding to the nearest value and saturating at MAX
+                pub const fn saturating_mul(self, rhs: Self) -> Self {
+                    match self.checked_mul(rhs) {
+                        Some(result) => result,
+                        None => Self::MAX,
+                    }
+                }
+
+                /// Divides, rounding to the nearest value and saturating at MAX. Panics when dividing by zero
+                pub const fn saturating_div(self, rhs: Self) -> Self {
+                    assert!(rhs.raw() != 0, "attempt to divide by zero");
+                    match self.checked_div(rhs) {
+                        Some(result) => result,
+                        None => Self::MAX,
+                    }
+                }
+
+                /// Converts to the nearest f64
+                pub fn to_f64(self) -> f64 {
+                    let _ = Self::RAW_MAX;
+                    self.raw() as f64 * pow2_f64(-(FRAC_BITS as i32))
+                }
+
+                /// Converts to the nearest f32
+                pub fn to_f32(self) -> f32 {
+                    let _ = Self::RAW_MAX;
+                    let raw = self.raw();
+                    // Raw values from 2^127 on round to 2^128, which f32 can't represent. Halving them
+                    // doesn't change the rounding, as the lowest bit is kept as a sticky bit
+                    let (raw, exponent) = if raw >> 127 != 0 {
+                        ((raw >> 1) | (raw & 1), 1 - FRAC_BITS as i32)
+                    } else {
+                        (raw, -(FRAC_BITS as i32))
+                    };
+                    // Scaling by a power of two is exact, even for subnormal results (their steps of
+                    // 2^-149 are finer than 2^-128). Smaller factors are split into two normal ones
+                    if exponent < -126 {
+                        raw as f32 * pow2_f32(-64) * pow2_f32(exponent + 64)
+                    } else {
+                        raw as f32 * pow2_f32(exponent)
+                    }
+                }
+
+                /// Converts from an f64, rounding to the nearest value. Panics if the value is
+                /// negative, NaN or too large
+                pub fn from_f64(value: f64) -> Self {
+                    match Self::try_from_f64(value) {
+                        Ok(result) => result,
+                        Err(_) => panic!("value is outside of the valid range"),
+                    }
+                }
+
+                /// Converts from an f64, rounding to the nearest value. Returns an error if the
+                /// value is negative, NaN or too large
+                pub fn try_from_f64(value: f64) -> Result<Self, TryNewError> {
+                    if value.is_nan() || value < 0.0 {
+                        return Err(TryNewError {});
+                    }
+                    let rounded = value * pow2_f64(FRAC_BITS as i32) + 0.5;
+                    if rounded >= pow2_f64(BITS as i32) {
+                        return Err(TryNewError {});
+                    }
+                    // As rounded is positive and in range, the cast truncates, i.e. rounds down
+                    let raw = rounded as u128;
+                    if raw <= Self::RAW_MAX {
+                        Ok(Self::from_raw(raw))
+                    } else {
+                        Err(TryNewError {})
+                    }
+                }
+
+                /// Converts from an f32, rounding to the nearest value. Panics if the value is
+                /// negative, NaN or too large
+                pub fn from_f32(value: f32) -> Self {
+                    Self::from_f64(value as f64)
+                }
+
+                /// Converts from an f32, rounding to the nearest value. Returns an error if the
+                /// value is negative, NaN or too large
+                pub fn try_from_f32(value: f32) -> Result<Self, TryNewError> {
+                    Self::try_from_f64(value as f64)
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> Add for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self::Output {
+                    match self.checked_add(rhs) {
+                        Some(result) => result,
+                        #[cfg(debug_assertions)]
+                        None => panic!("attempt to add with overflow"),
+                        #[cfg(not(debug_assertions))]
+                        None => self.wrapping_add(rhs),
+                    }
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> Sub for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self::Output {
+                    match self.checked_sub(rhs) {
+                        Some(result) => result,
+                        #[cfg(debug_assertions)]
+                        None => panic!("attempt to subtract with overflow"),
+                        #[cfg(not(debug_assertions))]
+                        None => self.wrapping_sub(rhs),
+                    }
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> Mul for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self::Output {
+                    match self.checked_mul(rhs) {
+                        Some(result) => result,
+                        #[cfg(debug_assertions)]
+                        None => panic!("attempt to multiply with overflow"),
+                        #[cfg(not(debug_assertions))]
+                        None => self.wrapping_mul(rhs),
+                    }
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> Div for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self::Output {
+                    match self.checked_div(rhs) {
+                        Some(result) => result,
+                        #[cfg(debug_assertions)]
+                        None if rhs.raw() != 0 => panic!("attempt to divide with overflow"),
+                        // wrapping_div panics on division by zero in every build
+                        None => self.wrapping_div(rhs),
+                    }
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> AddAssign for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> SubAssign for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> MulAssign for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> DivAssign for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+
+            // Prints the exact decimal value (every binary fraction has a finite decimal
+            // representation), unless a precision is given, in which case the value is rounded
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> Display for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    format_fixed(shr(self.raw(), FRAC_BITS), self.frac_part().raw(), FRAC_BITS, f)
+                }
+            }
+
+            impl<const BITS: usize, const INT_BITS: usize, const FRAC_BITS: usize> Debug for UFixed<UInt<$type, BITS>, INT_BITS, FRAC_BITS> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    Display::fmt(self, f)
+                }
+            }
+        )+
+    };
+}
+
+ufixed_impl!(u8, u16, u32, u64, u128, usize);
+
+// Longest text format_fixed produces: 39 integer digits, the dot and the fractional digits
+const MAX_FRAC_DIGITS: usize = 128;
+const FORMAT_BUFFER_LEN: usize = 40 + MAX_FRAC_DIGITS;
+
+fn format_fixed(
+    int: u128,
+    mut frac: u128,
+    mut frac_bits: usize,
+    f: &mut Formatter<'_>,
+) -> core::fmt::Result {
+    // Multiplying by 10 needs 4 spare bits. This only drops precision for UQ0.125 and wider
+    if frac_bits > 124 {
+        frac >>= frac_bits - 124;
+        frac_bits = 124;
+    }
+    let frac_mask = !shl(u128::MAX, frac_bits);
+
+    let mut buffer = [b'0'; FORMAT_BUFFER_LEN];
+    let mut frac_digits = [0u8; MAX_FRAC_DIGITS];
+    let mut frac_len = 0;
+    let digit_count = match f.precision() {
+        Some(precision) => precision.min(MAX_FRAC_DIGITS),
+        None => MAX_FRAC_DIGITS,
+    };
+    // Each step multiplies by 10 = 2 * 5, so frac becomes 0 after at most frac_bits steps
+    while frac_len < digit_count && (frac != 0 || f.precision().is_some()) {
+        frac *= 10;
+        frac_digits[frac_len] = shr(frac, frac_bits) as u8;
+        frac &= frac_mask;
+        frac_len += 1;
+    }
+
+    // Round to nearest (ties up) if there is a remainder
+    let mut int = int;
+    if frac_bits > 0 && frac >= (1 << (frac_bits - 1)) {
+        let mut i = frac_len;
+        loop {
+            if i == 0 {
+                int += 1;
+                break;
+            }
+            i -= 1;
+            if frac_digits[i] == 9 {
+                frac_digits[i] = 0;
+            } else {
+                frac_digits[i] += 1;
+                break;
+            }
+        }
+    }
+
+    let mut len = 0;
+    let mut int_digits = [0u8; 39];
+    let mut int_len = 0;
+    loop {
+        int_digits[int_len] = (int % 10) as u8;
+        int /= 10;
+        int_len += 1;
+        if int == 0 {
+            break;
+        }
+    }
+    while int_len > 0 {
+        int_len -= 1;
+        buffer[len] = b'0' + int_digits[int_len];
+        len += 1;
+    }
+    if frac_len > 0 {
+        buffer[len] = b'.';
+        len += 1;
+        for digit in &frac_digits[..frac_len] {
+            buffer[len] = b'0' + digit;
+            len += 1;
+        }
+    }
+
+    // The buffer only contains ASCII digits and the dot
+    let text = core::str::from_utf8(&buffer[..len]).map_err(|_| core::fmt::Error)?;
+    f.pad_integral(true, "", text)
+}
+
+macro_rules! ufixed_from_impl {
+    ($from:ident, [$($into:ident),+]) => {
+        $(
+            impl<
+                    const BITS: usize,
+                    const INT_BITS: usize,
+                    const FRAC_BITS: usize,
+                    const BITS_FROM: usize,
+                    const INT_BITS_FROM: usize,
+                    const FRAC_BITS_FROM: usize,
+                > From<UFixed<UInt<$from, BITS_FROM>, INT_BITS_FROM, FRAC_BITS_FROM>>
+                for UFixed<UInt<$into, BITS>, INT_BITS, FRAC_BITS>
+            {
+                #[inline]
+                fn from(value: UFixed<UInt<$from, BITS_FROM>, INT_BITS_FROM, FRAC_BITS_FROM>) -> Self {
+                    let _ = CompileTimeAssert::<INT_BITS_FROM, INT_BITS>::SMALLER_OR_EQUAL;
+                    let _ = CompileTimeAssert::<FRAC_BITS_FROM, FRAC_BITS>::SMALLER_OR_EQUAL;
+                    let _ = Self::RAW_MAX;
+                    Self::from_raw(shl(value.raw(), FRAC_BITS - FRAC_BITS_FROM))
+                }
+            }
+        )+
+    };
+}
+
+ufixed_from_impl!(u8, [u16, u32, u64, u128, usize]);
+ufixed_from_impl!(u16, [u8, u32, u64, u128, usize]);
+ufixed_from_impl!(u32, [u8, u16, u64, u128, usize]);
+ufixed_from_impl!(u64, [u8, u16, u32, u128, usize]);
+ufixed_from_impl!(u128, [u8, u16, u32, u64, usize]);
+ufixed_from_impl!(usize, [u8, u16, u32, u64, u128]);
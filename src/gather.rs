@@ -0,0 +1,176 @@
+use crate::{Number, UInt};
+
+/// Checks that MASK fits within the source type and that it selects exactly BITS bits, so that
+/// invalid masks fail to compile:
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::u6;
+/// // The mask selects 5 bits, not 6
+/// let value = u6::gather_u8::<0b1011_0011>(0xFF);
+/// ```
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::u6;
+/// // The mask doesn't fit into a u8
+/// let value = u6::new(0).scatter_u8::<0b11_1111_0000>();
+/// ```
+struct GatherMask<const MASK: u128, const BITS: usize, const SOURCE_BITS: usize> {}
+
+impl<const MASK: u128, const BITS: usize, const SOURCE_BITS: usize>
+    GatherMask<MASK, BITS, SOURCE_BITS>
+{
+    const VALID: () = {
+        assert!(
+            SOURCE_BITS >= 128 || MASK >> SOURCE_BITS == 0,
+            "MASK has more bits than the source type"
+        );
+        assert!(
+            MASK.count_ones() as usize == BITS,
+            "MASK has to have exactly BITS bits set"
+        );
+    };
+}
+
+// Portable parallel bit extract. It works on runs of contiguous bits in the mask, so a constant mask
+// turns into one shift and and per run
+#[inline]
+const fn gather_bits(value: u128, mask: u128) -> u128 {
+    let mut mask = mask;
+    let mut result = 0;
+    let mut result_bit = 0;
+    while mask != 0 {
+        let start = mask.trailing_zeros();
+        let length = (mask >> start).trailing_ones();
+        let run = u128::MAX >> (128 - length);
+        result |= ((value >> start) & run) << result_bit;
+        result_bit += length;
+        mask &= !(run << start);
+    }
+    result
+}
+
+// Portable parallel bit deposit, the inverse of gather_bits
+#[inline]
+const fn scatter_bits(value: u128, mask: u128) -> u128 {
+    let mut mask = mask;
+    let mut result = 0;
+    let mut value_bit = 0;
+    while mask != 0 {
+        let start = mask.trailing_zeros();
+        let length = (mask >> start).trailing_ones();
+        let run = u128::MAX >> (128 - length);
+        result |= ((value >> value_bit) & run) << start;
+        value_bit += length;
+        mask &= !(run << start);
+    }
+    result
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+#[inline]
+fn pext(value: u128, mask: u128) -> u128 {
+    use core::arch::x86_64::_pext_u64;
+    let low_mask = mask as u64;
+    let high_mask = (mask >> 64) as u64;
+    // Safety: The target supports BMI2
+    let low = unsafe { _pext_u64(value as u64, low_mask) } as u128;
+    if high_mask == 0 {
+        return low;
+    }
+    let high = unsafe { _pext_u64((value >> 64) as u64, high_mask) } as u128;
+    low | (high << low_mask.count_ones())
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+#[inline]
+fn pdep(value: u128, mask: u128) -> u128 {
+    use core::arch::x86_64::_pdep_u64;
+    let low_mask = mask as u64;
+    let high_mask = (mask >> 64) as u64;
+    // Safety: The target supports BMI2
+    let low = unsafe { _pdep_u64(value as u64, low_mask) } as u128;
+    if high_mask == 0 {
+        return low;
+    }
+    let high_value = (value >> low_mask.count_ones()) as u64;
+    let high = unsafe { _pdep_u64(high_value, high_mask) } as u128;
+    low | (high << 64)
+}
+
+// Const fns can't be generic over the source type on stable Rust, so there is one set of functions
+// for every native integer, named after it (like from_u8 and widen_to_u8). The BMI2 instructions
+// can't be used in const fns, so they get separate functions, which only exist if the target has them
+macro_rules! gather_impl {
+    ([$($type:ident),+], $sources:tt) => {
+        $( gather_impl!($type, $sources); )+
+    };
+    ($type:ident, [$(($source:ident, $gather_fn:ident, $scatter_fn:ident, $pext_fn:ident, $pdep_fn:ident)),+]) => {
+        impl<const BITS: usize> UInt<$type, BITS> {
+            $(
+                /// Gathers the bits of value that are set in MASK into a contiguous value, with the
+                /// lowest bit of the mask becoming the lowest bit of the result. This is a parallel
+                /// bit extract (pext) that can be used in const contexts. MASK has to have exactly
+                /// BITS bits set, otherwise this fails to compile.
+                ///
+                /// On x86_64 targets with BMI2, the pext function with the same suffix does the same
+                /// at runtime using the pext instruction
+                #[inline]
+                pub const fn $gather_fn<const MASK: u128>(value: $source) -> Self {
+                    let _ = GatherMask::<MASK, BITS, { <$source>::BITS as usize }>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    Self { value: gather_bits(value as u128, MASK) as $type }
+                }
+
+                /// Scatters the bits of this value to the bits that are set in MASK, starting with the
+                /// lowest bit. All other bits of the result are zero. This is a parallel bit deposit
+                /// (pdep) that can be used in const contexts. MASK has to have exactly BITS bits set,
+                /// otherwise this fails to compile.
+                ///
+                /// On x86_64 targets with BMI2, the pdep function with the same suffix does the same
+                /// at runtime using the pdep instruction
+                #[inline]
+                pub const fn $scatter_fn<const MASK: u128>(self) -> $source {
+                    let _ = GatherMask::<MASK, BITS, { <$source>::BITS as usize }>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    scatter_bits(self.value as u128, MASK) as $source
+                }
+
+                /// Same as the gather function with the same suffix, but uses the BMI2 instruction
+                /// pext. Only available on x86_64 targets with BMI2
+                #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+                #[inline]
+                pub fn $pext_fn<const MASK: u128>(value: $source) -> Self {
+                    let _ = GatherMask::<MASK, BITS, { <$source>::BITS as usize }>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    Self { value: pext(value as u128, MASK) as $type }
+                }
+
+                /// Same as the scatter function with the same suffix, but uses the BMI2 instruction
+                /// pdep. Only available on x86_64 targets with BMI2
+                #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+                #[inline]
+                pub fn $pdep_fn<const MASK: u128>(self) -> $source {
+                    let _ = GatherMask::<MASK, BITS, { <$source>::BITS as usize }>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    pdep(self.value as u128, MASK) as $source
+                }
+            )+
+        }
+    };
+}
+
+gather_impl!(
+    [u8, u16, u32, u64, u128, usize],
+    [
+        (u8, gather_u8, scatter_u8, pext_u8, pdep_u8),
+        (u16, gather_u16, scatter_u16, pext_u16, pdep_u16),
+        (u32, gather_u32, scatter_u32, pext_u32, pdep_u32),
+        (u64, gather_u64, scatter_u64, pext_u64, pdep_u64),
+        (u128, gather_u128, scatter_u128, pext_u128, pdep_u128),
+        (usize, gather_usize, scatter_usize, pext_usize, pdep_usize)
+    ]
+);
@@ -0,0 +1,380 @@
+use crate::UInt;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, UpperHex};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// An element of the finite field GF(2^BITS), e.g. `Gf<UInt<u8, 8>, 0x1B>` for the field used by
+/// AES or `Gf<u4, 0x3>` for GF(16). `T` is the UInt that holds the element.
+///
+/// `POLY` is the reduction polynomial without its leading x^BITS term, so AES's
+/// x^8 + x^4 + x^3 + x + 1 is `0x1B`. Multiplicative inverses (and therefore division) only exist
+/// if the polynomial is irreducible. This isn't checked, but [GfTables] (which requires a primitive
+/// polynomial) fails to build otherwise.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[repr(transparent)]
+pub struct Gf<T, const POLY: u128> {
+    value: T,
+}
+
+/// Logarithm and antilogarithm (exponentiation) tables of GF(2^BITS), which turn multiplication,
+/// division and inversion into table lookups. `SIZE` has to be `2^BITS`.
+///
+/// The tables are generated by a const fn, so they can be created at compile time:
+/// `const TABLES: GfTables<u4, 0x3, 16> = GfTables::<u4, 0x3, 16>::new();`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GfTables<T, const POLY: u128, const SIZE: usize> {
+    exp: [Gf<T, POLY>; SIZE],
+    log: [T; SIZE],
+}
+
+macro_rules! gf_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<const BITS: usize, const POLY: u128> Gf<UInt<$type, BITS>, POLY> {
+                // Validates the polynomial. This is queried by every constructor, so that invalid
+                // definitions fail to compile
+                const VALID: () = {
+                    assert!(POLY <= UInt::<$type, BITS>::MASK as u128, "POLY has to fit into BITS bits");
+                };
+
+                /// The additive identity
+                pub const ZERO: Self = Self::new(UInt { value: 0 });
+
+                /// The multiplicative identity
+                pub const ONE: Self = Self::new(UInt { value: 1 });
+
+                /// The polynomial x, which generates the multiplicative group if POLY is primitive
+                pub const GENERATOR: Self = Self::ONE.mul_x();
+
+                /// Creates a field element from its polynomial representation, where bit n is the
+                /// coefficient of x^n
+                #[inline]
+                pub const fn new(value: UInt<$type, BITS>) -> Self {
+                    let _ = Self::VALID;
+                    Self { value }
+                }
+
+                /// Returns the polynomial representation of the element
+                #[inline]
+                pub const fn get(self) -> UInt<$type, BITS> {
+                    self.value
+                }
+
+                /// Returns the type as a fundamental data type
+                #[inline]
+                pub const fn value(self) -> $type {
+                    self.value.value
+                }
+
+                /// Adds two elements, which is a xor of their coefficients
+                #[inline]
+                pub const fn add(self, rhs: Self) -> Self {
+                    Self { value: UInt { value: self.value.value ^ rhs.value.value } }
+                }
+
+                /// Subtracts two elements. As every element is its own additive inverse, this is
+                /// the same as [Self::add]
+                #[inline]
+                pub const fn sub(self, rhs: Self) -> Self {
+                    self.add(rhs)
+                }
+
+                // Multiplies by x, reducing by POLY if the x^BITS term would be set
+                #[inline]
+                const fn mul_x(self) -> Self {
+                    let value = self.value.value;
+                    let shifted = (value << 1) & UInt::<$type, BITS>::MASK;
+                    let value = if (value >> (BITS - 1)) & 1 != 0 {
+                        shifted ^ POLY as $type
+                    } else {
+                        shifted
+                    };
+                    Self { value: UInt { value } }
+                }
+
+                /// Multiplies two elements: A carry-less multiplication of the polynomials,
+                /// reduced by POLY
+                pub const fn mul(self, rhs: Self) -> Self {
+                    let mut factor = self;
+                    let mut remaining = rhs.value.value;
+                    let mut result = Self::ZERO;
+                    while remaining != 0 {
+                        if remaining & 1 != 0 {
+                            result = result.add(factor);
+                        }
+                        remaining >>= 1;
+                        factor = factor.mul_x();
+                    }
+                    result
+                }
+
+                /// Raises the element to the given power. `0^0` is 1
+                pub const fn pow(self, exp: u128) -> Self {
+                    let mut base = self;
+                    let mut exp = exp;
+                    let mut result = Self::ONE;
+                    while exp != 0 {
+                        if exp & 1 != 0 {
+                            result = result.mul(base);
+                        }
+                        exp >>= 1;
+                        base = base.mul(base);
+                    }
+                    result
+                }
+
+                /// Returns the multiplicative inverse or `None` for zero
+                pub const fn checked_inv(self) -> Option<Self> {
+                    if self.value.value == 0 {
+                        return None;
+                    }
+                    // The multiplicative group has 2^BITS - 1 elements, so a^(2^BITS - 2) is the
+                    // inverse of a
+                    Some(self.pow(UInt::<$type, BITS>::MASK as u128 - 1))
+                }
+
+                /// Returns the multiplicative inverse. Panics for zero
+                pub const fn inv(self) -> Self {
+                    match self.checked_inv() {
+                        Some(result) => result,
+                        None => panic!("attempt to invert zero"),
+                    }
+                }
+
+                /// Divides two elements, returning `None` if `rhs` is zero
+                pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                    match rhs.checked_inv() {
+                        Some(inverse) => Some(self.mul(inverse)),
+                        None => None,
+                    }
+                }
+
+                /// Divides two elements. Panics if `rhs` is zero
+                pub const fn div(self, rhs: Self) -> Self {
+                    self.mul(rhs.inv())
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128, const SIZE: usize> GfTables<UInt<$type, BITS>, POLY, SIZE> {
+                const VALID: () = {
+                    assert!(BITS < usize::BITS as usize && SIZE == 1 << BITS, "SIZE has to be 2^BITS");
+                };
+
+                /// Generates the tables. Panics (or fails to compile in a const context) if POLY is
+                /// not primitive
+                pub const fn new() -> Self {
+                    let _ = Self::VALID;
+                    let mut exp = [Gf::<UInt<$type, BITS>, POLY>::ZERO; SIZE];
+                    let mut log = [UInt::<$type, BITS> { value: 0 }; SIZE];
+                    let mut element = Gf::<UInt<$type, BITS>, POLY>::ONE;
+                    let mut i = 0;
+                    while i < SIZE - 1 {
+                        // A primitive polynomial generates every non-zero element exactly once
+                        // before returning to 1
+                        assert!(
+                            i == 0 || (element.value.value > 1 && log[element.value.value as usize].value == 0),
+                            "POLY is not primitive"
+                        );
+                        exp[i] = element;
+                        log[element.value.value as usize] = UInt { value: i as $type };
+                        element = element.mul_x();
+                        i += 1;
+                    }
+                    // x^(2^BITS - 1) is 1 again
+                    exp[SIZE - 1] = element;
+                    Self { exp, log }
+                }
+
+                /// The antilogarithm table, which maps n to x^n
+                #[inline]
+                pub const fn exp_table(&self) -> &[Gf<UInt<$type, BITS>, POLY>; SIZE] {
+                    &self.exp
+                }
+
+                /// The logarithm table, which maps a non-zero element to n with x^n = element. The
+                /// entry for zero is 0
+                #[inline]
+                pub const fn log_table(&self) -> &[UInt<$type, BITS>; SIZE] {
+                    &self.log
+                }
+
+                /// Returns x^n
+                #[inline]
+                pub const fn exp(&self, n: usize) -> Gf<UInt<$type, BITS>, POLY> {
+                    self.exp[n % (SIZE - 1)]
+                }
+
+                /// Returns n with x^n = value, or `None` for zero
+                #[inline]
+                pub const fn log(&self, value: Gf<UInt<$type, BITS>, POLY>) -> Option<UInt<$type, BITS>> {
+                    if value.value.value == 0 {
+                        None
+                    } else {
+                        Some(self.log[value.value.value as usize])
+                    }
+                }
+
+                /// Multiplies two elements using the tables
+                pub const fn mul(
+                    &self,
+                    lhs: Gf<UInt<$type, BITS>, POLY>,
+                    rhs: Gf<UInt<$type, BITS>, POLY>,
+                ) -> Gf<UInt<$type, BITS>, POLY> {
+                    match (self.log(lhs), self.log(rhs)) {
+                        (Some(lhs), Some(rhs)) => self.exp(lhs.value as usize + rhs.value as usize),
+                        _ => Gf::<UInt<$type, BITS>, POLY>::ZERO,
+                    }
+                }
+
+                /// Raises an element to the given power using the tables. `0^0` is 1
+                pub const fn pow(&self, value: Gf<UInt<$type, BITS>, POLY>, exp: u128) -> Gf<UInt<$type, BITS>, POLY> {
+                    match self.log(value) {
+                        Some(log) => {
+                            let order = (SIZE - 1) as u128;
+                            self.exp(((log.value as u128 * (exp % order)) % order) as usize)
+                        }
+                        None if exp == 0 => Gf::<UInt<$type, BITS>, POLY>::ONE,
+                        None => Gf::<UInt<$type, BITS>, POLY>::ZERO,
+                    }
+                }
+
+                /// Returns the multiplicative inverse using the tables, or `None` for zero
+                pub const fn checked_inv(&self, value: Gf<UInt<$type, BITS>, POLY>) -> Option<Gf<UInt<$type, BITS>, POLY>> {
+                    match self.log(value) {
+                        Some(log) => Some(self.exp(SIZE - 1 - log.value as usize)),
+                        None => None,
+                    }
+                }
+
+                /// Divides two elements using the tables, returning `None` if `rhs` is zero
+                pub const fn checked_div(
+                    &self,
+                    lhs: Gf<UInt<$type, BITS>, POLY>,
+                    rhs: Gf<UInt<$type, BITS>, POLY>,
+                ) -> Option<Gf<UInt<$type, BITS>, POLY>> {
+                    match (self.log(lhs), self.log(rhs)) {
+                        (Some(lhs), Some(rhs)) => Some(self.exp(SIZE - 1 + lhs.value as usize - rhs.value as usize)),
+                        (None, Some(_)) => Some(Gf::<UInt<$type, BITS>, POLY>::ZERO),
+                        (_, None) => None,
+                    }
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128, const SIZE: usize> Default for GfTables<UInt<$type, BITS>, POLY, SIZE> {
+                #[inline]
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> From<UInt<$type, BITS>> for Gf<UInt<$type, BITS>, POLY> {
+                #[inline]
+                fn from(value: UInt<$type, BITS>) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> From<Gf<UInt<$type, BITS>, POLY>> for UInt<$type, BITS> {
+                #[inline]
+                fn from(value: Gf<UInt<$type, BITS>, POLY>) -> Self {
+                    value.value
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> Add for Gf<UInt<$type, BITS>, POLY> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    self.add(rhs)
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> Sub for Gf<UInt<$type, BITS>, POLY> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    self.sub(rhs)
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> Mul for Gf<UInt<$type, BITS>, POLY> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    self.mul(rhs)
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> Div for Gf<UInt<$type, BITS>, POLY> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Self) -> Self {
+                    self.div(rhs)
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> AddAssign for Gf<UInt<$type, BITS>, POLY> {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = (*self).add(rhs);
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> SubAssign for Gf<UInt<$type, BITS>, POLY> {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = (*self).sub(rhs);
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> MulAssign for Gf<UInt<$type, BITS>, POLY> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = (*self).mul(rhs);
+                }
+            }
+
+            impl<const BITS: usize, const POLY: u128> DivAssign for Gf<UInt<$type, BITS>, POLY> {
+                #[inline]
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = (*self).div(rhs);
+                }
+            }
+        )+
+    };
+}
+
+gf_impl!(u8, u16, u32, u64, u128, usize);
+
+impl<T: Display, const POLY: u128> Display for Gf<T, POLY> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<T: Debug, const POLY: u128> Debug for Gf<T, POLY> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl<T: LowerHex, const POLY: u128> LowerHex for Gf<T, POLY> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        LowerHex::fmt(&self.value, f)
+    }
+}
+
+impl<T: UpperHex, const POLY: u128> UpperHex for Gf<T, POLY> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        UpperHex::fmt(&self.value, f)
+    }
+}
+
+impl<T: Binary, const POLY: u128> Binary for Gf<T, POLY> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Binary::fmt(&self.value, f)
+    }
+}
@@ -0,0 +1,234 @@
+//! Linear-feedback shift registers whose state is a `UInt`, e.g. a `u7` for PRBS7. Both the
+//! Fibonacci ([FibonacciLfsr]) and the Galois ([GaloisLfsr]) form are provided, along with the
+//! standard PRBS generators of ITU-T O.150 (e.g. [Prbs7]).
+//!
+//! The feedback taps are given as a mask in which bit n stands for the term x^(n+1) of the
+//! polynomial, so x^7 + x^6 + 1 is `0x60`. The highest tap has to be bit BITS - 1. Both forms
+//! produce a sequence with a period of 2^BITS - 1 if the polynomial is primitive.
+
+use crate::{Number, UInt, UnsignedNumber};
+use core::marker::PhantomData;
+
+/// An LFSR in Fibonacci form: On every step, the taps are xored into a new bit, which is shifted
+/// into the register from the right and is the output.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FibonacciLfsr<T, const TAPS: u128> {
+    state: T,
+}
+
+/// An LFSR in Galois form: On every step, the register is shifted right and the bit that is
+/// shifted out is the output. If it is set, the taps are xored into the register.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GaloisLfsr<T, const TAPS: u128> {
+    state: T,
+}
+
+/// PRBS7, x^7 + x^6 + 1
+pub type Prbs7 = FibonacciLfsr<crate::aliases::u7, 0x60>;
+
+/// PRBS9, x^9 + x^5 + 1
+pub type Prbs9 = FibonacciLfsr<crate::aliases::u9, 0x110>;
+
+/// PRBS11, x^11 + x^9 + 1
+pub type Prbs11 = FibonacciLfsr<crate::aliases::u11, 0x500>;
+
+/// PRBS15, x^15 + x^14 + 1
+pub type Prbs15 = FibonacciLfsr<crate::aliases::u15, 0x6000>;
+
+/// PRBS20, x^20 + x^3 + 1
+pub type Prbs20 = FibonacciLfsr<crate::aliases::u20, 0x80004>;
+
+/// PRBS23, x^23 + x^18 + 1
+pub type Prbs23 = FibonacciLfsr<crate::aliases::u23, 0x420000>;
+
+/// PRBS31, x^31 + x^28 + 1
+pub type Prbs31 = FibonacciLfsr<crate::aliases::u31, 0x48000000>;
+
+/// An iterator that groups the bits of another iterator into words, starting with the most
+/// significant bit. Created by `words()` of [FibonacciLfsr] and [GaloisLfsr].
+#[derive(Copy, Clone, Debug)]
+pub struct Words<I, W> {
+    bits: I,
+    word: PhantomData<W>,
+}
+
+impl<I: Iterator<Item = bool>, W: UnsignedNumber> Iterator for Words<I, W> {
+    type Item = W;
+
+    fn next(&mut self) -> Option<W> {
+        let mut word = W::ZERO;
+        for _ in 0..W::BITS {
+            let bit = if self.bits.next()? { W::ONE } else { W::ZERO };
+            word = word.wrapping_shl(1) | bit;
+        }
+        Some(word)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.bits.size_hint();
+        (lower / W::BITS, upper.map(|upper| upper / W::BITS))
+    }
+}
+
+macro_rules! lfsr_impl {
+    ($($type:ident),+) => {
+        $(
+            lfsr_impl!(@common FibonacciLfsr, $type);
+            lfsr_impl!(@common GaloisLfsr, $type);
+
+            impl<const BITS: usize, const TAPS: u128> FibonacciLfsr<UInt<$type, BITS>, TAPS> {
+                /// Advances the register by one step and returns the new bit
+                #[inline]
+                pub fn next_bit(&mut self) -> bool {
+                    // The feedback is kept as an integer: Shifting in a bool that is also returned has
+                    // been miscompiled by LLVM in release builds, dropping the bit from the state
+                    let feedback = (self.state.value & TAPS as $type).count_ones() as $type & 1;
+                    self.state.value = ((self.state.value << 1) | feedback) & UInt::<$type, BITS>::MASK;
+                    feedback != 0
+                }
+            }
+
+            impl<const BITS: usize, const TAPS: u128> GaloisLfsr<UInt<$type, BITS>, TAPS> {
+                /// Advances the register by one step and returns the bit that was shifted out
+                #[inline]
+                pub fn next_bit(&mut self) -> bool {
+                    let output = self.state.value & 1 != 0;
+                    self.state.value >>= 1;
+                    if output {
+                        self.state.value ^= TAPS as $type;
+                    }
+                    output
+                }
+            }
+        )+
+    };
+    (@common $lfsr:ident, $type:ident) => {
+        impl<const BITS: usize, const TAPS: u128> $lfsr<UInt<$type, BITS>, TAPS> {
+            // Validates the taps. This is queried by every constructor, so that invalid definitions
+            // fail to compile
+            const VALID: () = {
+                assert!(
+                    TAPS <= UInt::<$type, BITS>::MASK as u128 && (TAPS >> (BITS - 1)) & 1 != 0,
+                    "TAPS has to fit into BITS bits and contain bit BITS - 1"
+                );
+            };
+
+            // Both forms advance the state by multiplying it with a matrix whose characteristic
+            // polynomial is x^BITS plus the reversed taps. This is that polynomial without x^BITS
+            const CHARACTERISTIC: $type = (TAPS as $type).reverse_bits() >> (<$type>::BITS as usize - BITS);
+
+            /// Creates an LFSR with the given state. Panics if the seed is zero, as the register
+            /// would stay zero forever
+            #[inline]
+            pub const fn new(seed: UInt<$type, BITS>) -> Self {
+                let _ = Self::VALID;
+                assert!(seed.value != 0, "seed must not be zero");
+                Self { state: seed }
+            }
+
+            /// Returns the current state of the register
+            #[inline]
+            pub const fn state(&self) -> UInt<$type, BITS> {
+                self.state
+            }
+
+            /// Returns the next `W::BITS` output bits as a word, starting with the most significant
+            /// bit
+            pub fn next_word<W: UnsignedNumber>(&mut self) -> W {
+                let mut word = W::ZERO;
+                for _ in 0..W::BITS {
+                    let bit = if self.next_bit() { W::ONE } else { W::ZERO };
+                    word = word.wrapping_shl(1) | bit;
+                }
+                word
+            }
+
+            /// Returns an iterator over the output grouped into words of type `W` (e.g. `u8` for
+            /// bytes). The first bit becomes the most significant bit of the first word
+            #[inline]
+            pub fn words<W: UnsignedNumber>(self) -> Words<Self, W> {
+                Words {
+                    bits: self,
+                    word: PhantomData,
+                }
+            }
+
+            /// Advances the register by the given number of steps, as if `next_bit` was called that
+            /// many times. This takes time proportional to BITS * log(steps)
+            pub fn jump(&mut self, steps: u128) {
+                // By Cayley-Hamilton, the state after n steps is a linear combination of the states
+                // after 0..BITS steps, with the coefficients of x^n mod the characteristic polynomial
+                let mut coefficients = Self::pow_x_mod(steps);
+                let mut result: $type = 0;
+                while coefficients != 0 {
+                    if coefficients & 1 != 0 {
+                        result ^= self.state.value;
+                    }
+                    coefficients >>= 1;
+                    self.next_bit();
+                }
+                self.state.value = result;
+            }
+
+            // Multiplies two polynomials modulo the characteristic polynomial
+            const fn mul_mod(a: $type, b: $type) -> $type {
+                let mut a = a;
+                let mut b = b;
+                let mut result = 0;
+                while b != 0 {
+                    if b & 1 != 0 {
+                        result ^= a;
+                    }
+                    b >>= 1;
+                    let carry = (a >> (BITS - 1)) & 1 != 0;
+                    a = (a << 1) & UInt::<$type, BITS>::MASK;
+                    if carry {
+                        a ^= Self::CHARACTERISTIC;
+                    }
+                }
+                result
+            }
+
+            // Returns x^exp modulo the characteristic polynomial
+            const fn pow_x_mod(exp: u128) -> $type {
+                // x itself, which is reduced for a single bit
+                let mut base = if BITS == 1 { Self::CHARACTERISTIC } else { 2 };
+                let mut exp = exp;
+                let mut result = 1;
+                while exp != 0 {
+                    if exp & 1 != 0 {
+                        result = Self::mul_mod(result, base);
+                    }
+                    exp >>= 1;
+                    base = Self::mul_mod(base, base);
+                }
+                result
+            }
+        }
+
+        impl<const BITS: usize, const TAPS: u128> Default for $lfsr<UInt<$type, BITS>, TAPS> {
+            /// Creates an LFSR whose bits are all set, which is the usual seed of PRBS generators
+            #[inline]
+            fn default() -> Self {
+                Self::new(UInt::<$type, BITS>::MAX)
+            }
+        }
+
+        impl<const BITS: usize, const TAPS: u128> Iterator for $lfsr<UInt<$type, BITS>, TAPS> {
+            type Item = bool;
+
+            #[inline]
+            fn next(&mut self) -> Option<bool> {
+                Some(self.next_bit())
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (usize::MAX, None)
+            }
+        }
+    };
+}
+
+lfsr_impl!(u8, u16, u32, u64, u128, usize);
@@ -1,9 +1,37 @@
+//! Unsigned integers with an arbitrary number of bits, like `u5` or `u120`. Each of them is a
+//! [UInt] that holds its value in a native integer, its base data type: `u5` is a `UInt<u8, 5>`
+//! and `u120` a `UInt<u128, 120>`.
+//!
+//! Most functions are const. As const fns can't convert between generic base data types on stable
+//! Rust, functions that combine UInts of different widths fix the base data types involved:
+//!
+//! - Concatenating and splitting names the base data type of the parts, e.g. `u12::concat_u8`.
+//! - Gathering and scattering bits names the native integer they come from, e.g. `u6::gather_u32`.
+//! - Morton keys use coordinates with half as many bits in their base data type as the key's
+//!   (u8 for a u8 key). This fits every 2D key, but a 3D key is up to four times as wide as its
+//!   coordinates, so some aliases don't compose: A `u24` key (in a u32) takes three `UInt<u16, 8>`
+//!   instead of three `u8`, and a `u18` key takes three `UInt<u16, 6>` instead of three `u6`.
+//!   Coordinates are converted with `from_u8`, `widen_to_u16` and friends:
+//!
+//! ```
+//! use arbitrary_int::{u24, UInt};
+//! let (x, y, z): (u8, u8, u8) = (1, 2, 3);
+//! let key = u24::interleave_3d(
+//!     UInt::<u16, 8>::from_u8(x),
+//!     UInt::<u16, 8>::from_u8(y),
+//!     UInt::<u16, 8>::from_u8(z),
+//! );
+//! let (x, _, _): (UInt<u16, 8>, _, _) = key.deinterleave_3d();
+//! assert_eq!(x.as_u8(), 1);
+//! ```
+
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(
     feature = "const_convert_and_const_trait_impl",
     feature(const_convert, const_trait_impl)
 )]
 #![cfg_attr(feature = "step_trait", feature(step_trait))]
+#![cfg_attr(feature = "const_param_ty", feature(adt_const_params))]
 
 #[cfg(all(feature = "borsh", not(feature = "std")))]
 extern crate alloc;
@@ -12,8 +40,6 @@ use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
 use core::hash::{Hash, Hasher};
 #[cfg(feature = "step_trait")]
 use core::iter::Step;
-#[cfg(feature = "num-traits")]
-use core::num::Wrapping;
 use core::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
     Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
@@ -30,6 +56,38 @@ use std::{collections::BTreeMap, string::ToString};
 #[cfg(feature = "schemars")]
 use schemars::JsonSchema;
 
+mod atomic;
+mod concat;
+pub mod crc;
+mod dyn_uint;
+mod encoding;
+mod fixed;
+mod gather;
+mod gf;
+pub mod lfsr;
+mod match_uint;
+mod morton;
+mod niche;
+mod nonzero;
+mod ranged;
+pub mod register;
+mod saturating;
+mod serial;
+mod table;
+mod wrapping;
+
+pub use atomic::{AtomicStorage, AtomicUInt};
+pub use dyn_uint::DynUInt;
+pub use fixed::UFixed;
+pub use gf::{Gf, GfTables};
+#[doc(hidden)]
+pub use match_uint::{match_arm_count, match_value, MatchArms};
+pub use niche::{NicheUInt, NicheWidth};
+pub use nonzero::{NonZeroStorage, NonZeroUInt};
+pub use ranged::Ranged;
+pub use saturating::Saturating;
+pub use wrapping::Wrapping;
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TryNewError;
 
@@ -39,6 +97,16 @@ impl Display for TryNewError {
     }
 }
 
+/// Returned when decoding a value that isn't a valid encoding, e.g. BCD with a nibble above 9
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InvalidEncodingError;
+
+impl Display for InvalidEncodingError {
+    fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "Value is not a valid encoding")
+    }
+}
+
 #[cfg_attr(feature = "const_convert_and_const_trait_impl", const_trait)]
 pub trait Number: Sized {
     type UnderlyingType: Debug
@@ -110,7 +178,301 @@ macro_rules! impl_number_native {
     };
 }
 
-impl_number_native!(u8, u16, u32, u64, u128);
+impl_number_native!(u8, u16, u32, u64, u128, usize);
+
+/// Companion to [Number] that exposes operators and the arithmetic and bit-operation methods of
+/// unsigned integers. It is implemented for the native types `u8` to `u128` and `usize` as well as
+/// for every `UInt`, so that algorithms can be written once for both native and arbitrary bit
+/// widths.
+pub trait UnsignedNumber:
+    Number
+    + Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Div<Output = Self>
+    + DivAssign
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + ShlAssign<u32>
+    + Shr<u32, Output = Self>
+    + ShrAssign<u32>
+{
+    /// The value 0
+    const ZERO: Self;
+
+    /// The value 1
+    const ONE: Self;
+
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    fn wrapping_div(self, rhs: Self) -> Self;
+
+    fn wrapping_shl(self, rhs: u32) -> Self;
+
+    fn wrapping_shr(self, rhs: u32) -> Self;
+
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    fn saturating_div(self, rhs: Self) -> Self;
+
+    fn saturating_pow(self, exp: u32) -> Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    fn checked_shl(self, rhs: u32) -> Option<Self>;
+
+    fn checked_shr(self, rhs: u32) -> Option<Self>;
+
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+
+    fn overflowing_div(self, rhs: Self) -> (Self, bool);
+
+    fn overflowing_shl(self, rhs: u32) -> (Self, bool);
+
+    fn overflowing_shr(self, rhs: u32) -> (Self, bool);
+
+    /// Reverses the order of bits in the integer
+    fn reverse_bits(self) -> Self;
+
+    /// Returns the number of ones in the binary representation of self
+    fn count_ones(self) -> u32;
+
+    /// Returns the number of zeros in the binary representation of self
+    fn count_zeros(self) -> u32;
+
+    /// Returns the number of leading ones in the binary representation of self
+    fn leading_ones(self) -> u32;
+
+    /// Returns the number of leading zeros in the binary representation of self
+    fn leading_zeros(self) -> u32;
+
+    /// Returns the number of trailing ones in the binary representation of self
+    fn trailing_ones(self) -> u32;
+
+    /// Returns the number of trailing zeros in the binary representation of self
+    fn trailing_zeros(self) -> u32;
+
+    /// Shifts the bits to the left by a specified amount, wrapping the truncated bits to the end
+    fn rotate_left(self, n: u32) -> Self;
+
+    /// Shifts the bits to the right by a specified amount, wrapping the truncated bits to the beginning
+    fn rotate_right(self, n: u32) -> Self;
+}
+
+// Native types and UInt provide all of these as inherent (const) functions, which take precedence
+// over the trait functions of the same name. So all we have to do is forward.
+macro_rules! unsigned_number_fns {
+    () => {
+        #[inline]
+        fn wrapping_add(self, rhs: Self) -> Self {
+            Self::wrapping_add(self, rhs)
+        }
+
+        #[inline]
+        fn wrapping_sub(self, rhs: Self) -> Self {
+            Self::wrapping_sub(self, rhs)
+        }
+
+        #[inline]
+        fn wrapping_mul(self, rhs: Self) -> Self {
+            Self::wrapping_mul(self, rhs)
+        }
+
+        #[inline]
+        fn wrapping_div(self, rhs: Self) -> Self {
+            Self::wrapping_div(self, rhs)
+        }
+
+        #[inline]
+        fn wrapping_shl(self, rhs: u32) -> Self {
+            Self::wrapping_shl(self, rhs)
+        }
+
+        #[inline]
+        fn wrapping_shr(self, rhs: u32) -> Self {
+            Self::wrapping_shr(self, rhs)
+        }
+
+        #[inline]
+        fn saturating_add(self, rhs: Self) -> Self {
+            Self::saturating_add(self, rhs)
+        }
+
+        #[inline]
+        fn saturating_sub(self, rhs: Self) -> Self {
+            Self::saturating_sub(self, rhs)
+        }
+
+        #[inline]
+        fn saturating_mul(self, rhs: Self) -> Self {
+            Self::saturating_mul(self, rhs)
+        }
+
+        #[inline]
+        fn saturating_div(self, rhs: Self) -> Self {
+            Self::saturating_div(self, rhs)
+        }
+
+        #[inline]
+        fn saturating_pow(self, exp: u32) -> Self {
+            Self::saturating_pow(self, exp)
+        }
+
+        #[inline]
+        fn checked_add(self, rhs: Self) -> Option<Self> {
+            Self::checked_add(self, rhs)
+        }
+
+        #[inline]
+        fn checked_sub(self, rhs: Self) -> Option<Self> {
+            Self::checked_sub(self, rhs)
+        }
+
+        #[inline]
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            Self::checked_mul(self, rhs)
+        }
+
+        #[inline]
+        fn checked_div(self, rhs: Self) -> Option<Self> {
+            Self::checked_div(self, rhs)
+        }
+
+        #[inline]
+        fn checked_shl(self, rhs: u32) -> Option<Self> {
+            Self::checked_shl(self, rhs)
+        }
+
+        #[inline]
+        fn checked_shr(self, rhs: u32) -> Option<Self> {
+            Self::checked_shr(self, rhs)
+        }
+
+        #[inline]
+        fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+            Self::overflowing_add(self, rhs)
+        }
+
+        #[inline]
+        fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+            Self::overflowing_sub(self, rhs)
+        }
+
+        #[inline]
+        fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+            Self::overflowing_mul(self, rhs)
+        }
+
+        #[inline]
+        fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+            Self::overflowing_div(self, rhs)
+        }
+
+        #[inline]
+        fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+            Self::overflowing_shl(self, rhs)
+        }
+
+        #[inline]
+        fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+            Self::overflowing_shr(self, rhs)
+        }
+
+        #[inline]
+        fn reverse_bits(self) -> Self {
+            Self::reverse_bits(self)
+        }
+
+        #[inline]
+        fn count_ones(self) -> u32 {
+            Self::count_ones(self)
+        }
+
+        #[inline]
+        fn count_zeros(self) -> u32 {
+            Self::count_zeros(self)
+        }
+
+        #[inline]
+        fn leading_ones(self) -> u32 {
+            Self::leading_ones(self)
+        }
+
+        #[inline]
+        fn leading_zeros(self) -> u32 {
+            Self::leading_zeros(self)
+        }
+
+        #[inline]
+        fn trailing_ones(self) -> u32 {
+            Self::trailing_ones(self)
+        }
+
+        #[inline]
+        fn trailing_zeros(self) -> u32 {
+            Self::trailing_zeros(self)
+        }
+
+        #[inline]
+        fn rotate_left(self, n: u32) -> Self {
+            Self::rotate_left(self, n)
+        }
+
+        #[inline]
+        fn rotate_right(self, n: u32) -> Self {
+            Self::rotate_right(self, n)
+        }
+    };
+}
+
+macro_rules! impl_unsigned_number_native {
+    ($( $type:ty ),+) => {
+        $(
+            impl UnsignedNumber for $type {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                unsigned_number_fns!();
+            }
+        )+
+    };
+}
+
+impl_unsigned_number_native!(u8, u16, u32, u64, u128, usize);
 
 struct CompileTimeAssert<const A: usize, const B: usize> {}
 
@@ -118,9 +480,15 @@ impl<const A: usize, const B: usize> CompileTimeAssert<A, B> {
     pub const SMALLER_OR_EQUAL: () = {
         assert!(A <= B);
     };
+
+    /// 2^A <= B, e.g. every value of a UInt with A bits is a valid index into an array of length B
+    pub const POWER_OF_TWO_SMALLER_OR_EQUAL: () = {
+        assert!(A < usize::BITS as usize && (1 << A) <= B);
+    };
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Default, Ord, PartialOrd)]
+#[cfg_attr(feature = "const_param_ty", derive(core::marker::ConstParamTy))]
 pub struct UInt<T, const BITS: usize> {
     value: T,
 }
@@ -237,7 +605,7 @@ macro_rules! uint_impl_num {
     };
 }
 
-uint_impl_num!(u8, u16, u32, u64, u128);
+uint_impl_num!(u8, u16, u32, u64, u128, usize);
 
 macro_rules! uint_impl {
     ($($type:ident),+) => {
@@ -352,6 +720,15 @@ macro_rules! uint_impl {
                     UInt::<$type, BITS_RESULT> { value: self.value }
                 }
 
+                /// Creates a UInt from one with fewer bits but with the same base data type. This is
+                /// the inverse of [widen](Self::widen)
+                pub const fn from_uint<const BITS_FROM: usize>(value: UInt<$type, BITS_FROM>) -> Self {
+                    let _ = CompileTimeAssert::<BITS_FROM, BITS>::SMALLER_OR_EQUAL;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    Self { value: value.value }
+                }
+
                 pub const fn wrapping_add(self, rhs: Self) -> Self {
                     let sum = self.value.wrapping_add(rhs.value);
                     Self {
@@ -667,7 +1044,22 @@ macro_rules! uint_impl {
     };
 }
 
-uint_impl!(u8, u16, u32, u64, u128);
+uint_impl!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! uint_impl_unsigned_number {
+    ($($type:ident),+) => {
+        $(
+            impl<const BITS: usize> UnsignedNumber for UInt<$type, BITS> {
+                const ZERO: Self = Self { value: 0 };
+                const ONE: Self = Self::new(1);
+
+                unsigned_number_fns!();
+            }
+        )+
+    };
+}
+
+uint_impl_unsigned_number!(u8, u16, u32, u64, u128, usize);
 
 // Arithmetic implementations
 impl<T, const BITS: usize> Add for UInt<T, BITS>
@@ -1265,11 +1657,11 @@ where
         + Shr<usize, Output = T>
         + Shl<usize, Output = T>
         + From<u8>,
-    Wrapping<T>: Add<Wrapping<T>, Output = Wrapping<T>>,
+    core::num::Wrapping<T>: Add<core::num::Wrapping<T>, Output = core::num::Wrapping<T>>,
 {
     #[inline]
     fn wrapping_add(&self, rhs: &Self) -> Self {
-        let sum = (Wrapping(self.value) + Wrapping(rhs.value)).0;
+        let sum = (core::num::Wrapping(self.value) + core::num::Wrapping(rhs.value)).0;
         Self {
             value: sum & Self::MASK,
         }
@@ -1290,11 +1682,11 @@ where
         + Shr<usize, Output = T>
         + Shl<usize, Output = T>
         + From<u8>,
-    Wrapping<T>: Sub<Wrapping<T>, Output = Wrapping<T>>,
+    core::num::Wrapping<T>: Sub<core::num::Wrapping<T>, Output = core::num::Wrapping<T>>,
 {
     #[inline]
     fn wrapping_sub(&self, rhs: &Self) -> Self {
-        let sum = (Wrapping(self.value) - Wrapping(rhs.value)).0;
+        let sum = (core::num::Wrapping(self.value) - core::num::Wrapping(rhs.value)).0;
         Self {
             value: sum & Self::MASK,
         }
@@ -1433,6 +1825,112 @@ bytes_operation_impl!(
 
 // Conversions
 
+// From and Into can only be used in const contexts through const_convert_and_const_trait_impl, which
+// requires an old nightly compiler. The following functions provide the same conversions as inherent
+// const functions, so that they are available on stable as well.
+macro_rules! const_conversion_impl {
+    ([$($type:ident),+], $others:tt) => {
+        $( const_conversion_impl!($type, $others); )+
+    };
+    ($type:ident, [$(($other:ident, $from_fn:ident, $as_fn:ident, $widen_fn:ident)),+]) => {
+        impl<const BITS: usize> UInt<$type, BITS> {
+            $(
+                /// Creates an instance from a native integer. Like `From`, this fails to compile if the
+                /// native type has more bits than this type, but it can also be used in const contexts
+                #[inline]
+                pub const fn $from_fn(value: $other) -> Self {
+                    let _ = CompileTimeAssert::<{ <$other>::BITS as usize }, BITS>::SMALLER_OR_EQUAL;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    Self { value: value as $type }
+                }
+
+                /// Converts to a native integer. Like `From`, this fails to compile if the native type
+                /// has fewer bits than this type, but it can also be used in const contexts
+                #[inline]
+                pub const fn $as_fn(self) -> $other {
+                    let _ = CompileTimeAssert::<BITS, { <$other>::BITS as usize }>::SMALLER_OR_EQUAL;
+                    self.value as $other
+                }
+
+                /// Returns a UInt with a wider (or equal) bit depth, using a different base data type.
+                /// Like `From`, this fails to compile if the result has fewer bits, but it can also be
+                /// used in const contexts
+                #[inline]
+                pub const fn $widen_fn<const BITS_RESULT: usize>(self) -> UInt<$other, BITS_RESULT> {
+                    let _ = CompileTimeAssert::<BITS, BITS_RESULT>::SMALLER_OR_EQUAL;
+                    // Query MAX of the result to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = UInt::<$other, BITS_RESULT>::MAX;
+                    UInt::<$other, BITS_RESULT> { value: self.value as $other }
+                }
+            )+
+        }
+    };
+}
+
+const_conversion_impl!(
+    [u8, u16, u32, u64, u128, usize],
+    [
+        (u8, from_u8, as_u8, widen_to_u8),
+        (u16, from_u16, as_u16, widen_to_u16),
+        (u32, from_u32, as_u32, widen_to_u32),
+        (u64, from_u64, as_u64, widen_to_u64),
+        (u128, from_u128, as_u128, widen_to_u128),
+        (usize, from_usize, as_usize, widen_to_usize)
+    ]
+);
+
+// Reinterpretation as two's complement. There is no signed UInt, but fields in bitfields often
+// are signed, so these treat the topmost bit as the sign bit.
+macro_rules! sign_extend_impl {
+    ([$($type:ident),+], $signed:tt) => {
+        $( sign_extend_impl!($type, $signed); )+
+    };
+    ($type:ident, [$(($signed:ident, $sign_extend_fn:ident)),+]) => {
+        impl<const BITS: usize> UInt<$type, BITS> {
+            $(
+                /// Interprets the value as a two's complement number (with the topmost bit being
+                /// the sign bit) and sign-extends it into a native signed integer. Fails to compile
+                /// if the native type has fewer bits than this type
+                #[inline]
+                pub const fn $sign_extend_fn(self) -> $signed {
+                    let _ = CompileTimeAssert::<BITS, { <$signed>::BITS as usize }>::SMALLER_OR_EQUAL;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    let shift = <$signed>::BITS - BITS as u32;
+                    // Move the sign bit into the topmost bit, then perform an arithmetic shift back
+                    ((self.value as $signed) << shift) >> shift
+                }
+            )+
+
+            /// Truncates a signed value into the bit pattern of this type, the same way an `as`
+            /// cast between native types does. This is the inverse of the `sign_extend_to_*`
+            /// functions: `u12::from_signed_wrapping(-1)` is `u12::MAX`.
+            ///
+            /// This takes an `i128` rather than an `i32` so that it covers every width up to
+            /// `u127`. Any smaller signed value converts losslessly with `.into()` or `as i128`
+            #[inline]
+            pub const fn from_signed_wrapping(value: i128) -> Self {
+                Self {
+                    value: (value as $type) & Self::MASK,
+                }
+            }
+        }
+    };
+}
+
+sign_extend_impl!(
+    [u8, u16, u32, u64, u128, usize],
+    [
+        (i8, sign_extend_to_i8),
+        (i16, sign_extend_to_i16),
+        (i32, sign_extend_to_i32),
+        (i64, sign_extend_to_i64),
+        (i128, sign_extend_to_i128),
+        (isize, sign_extend_to_isize)
+    ]
+);
+
 #[cfg(feature = "const_convert_and_const_trait_impl")]
 macro_rules! from_arbitrary_int_impl {
     ($from:ty, [$($into:ty),+]) => {
@@ -1443,6 +1941,9 @@ macro_rules! from_arbitrary_int_impl {
                 #[inline]
                 fn from(item: UInt<$from, BITS_FROM>) -> Self {
                     let _ = CompileTimeAssert::<BITS_FROM, BITS>::SMALLER_OR_EQUAL;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <usize, 40>
+                    // on a 32 bit platform)
+                    let _ = Self::MAX;
                     Self { value: item.value as $into }
                 }
             }
@@ -1460,6 +1961,9 @@ macro_rules! from_arbitrary_int_impl {
                 #[inline]
                 fn from(item: UInt<$from, BITS_FROM>) -> Self {
                     let _ = CompileTimeAssert::<BITS_FROM, BITS>::SMALLER_OR_EQUAL;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <usize, 40>
+                    // on a 32 bit platform)
+                    let _ = Self::MAX;
                     Self { value: item.value as $into }
                 }
             }
@@ -1513,11 +2017,12 @@ macro_rules! from_native_impl {
     };
 }
 
-from_arbitrary_int_impl!(u8, [u16, u32, u64, u128]);
-from_arbitrary_int_impl!(u16, [u8, u32, u64, u128]);
-from_arbitrary_int_impl!(u32, [u8, u16, u64, u128]);
-from_arbitrary_int_impl!(u64, [u8, u16, u32, u128]);
-from_arbitrary_int_impl!(u128, [u8, u32, u64, u16]);
+from_arbitrary_int_impl!(u8, [u16, u32, u64, u128, usize]);
+from_arbitrary_int_impl!(u16, [u8, u32, u64, u128, usize]);
+from_arbitrary_int_impl!(u32, [u8, u16, u64, u128, usize]);
+from_arbitrary_int_impl!(u64, [u8, u16, u32, u128, usize]);
+from_arbitrary_int_impl!(u128, [u8, u32, u64, u16, usize]);
+from_arbitrary_int_impl!(usize, [u8, u16, u32, u64, u128]);
 
 from_native_impl!(u8, [u8, u16, u32, u64, u128]);
 from_native_impl!(u16, [u8, u16, u32, u64, u128]);
@@ -1525,24 +2030,198 @@ from_native_impl!(u32, [u8, u16, u32, u64, u128]);
 from_native_impl!(u64, [u8, u16, u32, u64, u128]);
 from_native_impl!(u128, [u8, u16, u32, u64, u128]);
 
+// The number of bits of usize depends on the platform. Conversions into UInt<usize, BITS> (whose
+// BITS are fixed) and from it into native types can be checked at compile time. Conversions
+// between usize itself and a UInt based on a wider type are checked at runtime instead, so that
+// code which compiles on one platform doesn't fail to compile on another.
+macro_rules! usize_conversion_impl {
+    ([$($native:ident),+], [$($narrow:ident),+], [$($wide:ident),+]) => {
+        $(
+            impl<const BITS: usize> From<$native> for UInt<usize, BITS> {
+                #[inline]
+                fn from(from: $native) -> Self {
+                    let _ = CompileTimeAssert::<{ <$native>::BITS as usize }, BITS>::SMALLER_OR_EQUAL;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <usize, 40>
+                    // on a 32 bit platform)
+                    let _ = Self::MAX;
+                    Self { value: from as usize }
+                }
+            }
+
+            impl<const BITS: usize> From<UInt<usize, BITS>> for $native {
+                #[inline]
+                fn from(from: UInt<usize, BITS>) -> Self {
+                    let _ = CompileTimeAssert::<BITS, { <$native>::BITS as usize }>::SMALLER_OR_EQUAL;
+                    from.value as $native
+                }
+            }
+
+            impl<const BITS: usize> TryFrom<usize> for UInt<$native, BITS> {
+                type Error = TryNewError;
+
+                #[inline]
+                fn try_from(value: usize) -> Result<Self, Self::Error> {
+                    if value as u128 <= Self::MAX.value as u128 {
+                        Ok(Self { value: value as $native })
+                    } else {
+                        Err(TryNewError {})
+                    }
+                }
+            }
+        )+
+
+        $(
+            impl<const BITS: usize> From<UInt<$narrow, BITS>> for usize {
+                #[inline]
+                fn from(from: UInt<$narrow, BITS>) -> Self {
+                    from.value as usize
+                }
+            }
+        )+
+
+        $(
+            impl<const BITS: usize> TryFrom<UInt<$wide, BITS>> for usize {
+                type Error = TryNewError;
+
+                #[inline]
+                fn try_from(from: UInt<$wide, BITS>) -> Result<Self, Self::Error> {
+                    usize::try_from(from.value).map_err(|_| TryNewError {})
+                }
+            }
+        )+
+    };
+}
+
+// usize has at least 16 bits, so UInts based on u8 and u16 can always be converted into it
+usize_conversion_impl!([u8, u16, u32, u64, u128], [u8, u16], [u32, u64, u128]);
+
+impl<const BITS: usize> TryFrom<usize> for UInt<usize, BITS> {
+    type Error = TryNewError;
+
+    #[inline]
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl<const BITS: usize> From<UInt<usize, BITS>> for usize {
+    #[inline]
+    fn from(from: UInt<usize, BITS>) -> Self {
+        from.value
+    }
+}
+
+/// Type-level bit count. Together with [Width], this allows generic code that only knows a
+/// number of bits to name the matching type, e.g. `<Bits<13> as Width>::UInt` is `u13`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Bits<const N: usize>;
+
+/// Maps a [Bits] to its canonical types. Implemented for `Bits<1>` up to `Bits<128>`.
+pub trait Width {
+    /// The smallest native data type that can hold the given number of bits (e.g. `u16` for `Bits<13>`)
+    type Storage: Number<UnderlyingType = Self::Storage>
+        + Copy
+        + Debug
+        + From<u8>
+        + TryFrom<u16>
+        + TryFrom<u32>
+        + TryFrom<u64>
+        + TryFrom<u128>;
+
+    /// The UInt with the given number of bits, backed by [Width::Storage]. Note that for
+    /// `Bits<8>`, `Bits<16>` etc. this is `UInt<u8, 8>` and not the native type.
+    type UInt: Number<UnderlyingType = Self::Storage> + Copy;
+}
+
+/// The canonical UInt for a given number of bits, e.g. `UIntFor<13>` is `u13`
+pub type UIntFor<const N: usize> = <Bits<N> as Width>::UInt;
+
+/// The canonical storage type for a given number of bits, e.g. `StorageFor<13>` is `u16`
+pub type StorageFor<const N: usize> = <Bits<N> as Width>::Storage;
+
+macro_rules! width_native_impl {
+    ($(($storage:ty, $bits:expr)),+) => {
+        $(
+            impl Width for Bits<$bits> {
+                type Storage = $storage;
+                type UInt = UInt<$storage, $bits>;
+            }
+        )+
+    };
+}
+
+width_native_impl!((u8, 8), (u16, 16), (u32, 32), (u64, 64), (u128, 128));
+
 // Define type aliases like u1, u63 and u80 using the smallest possible underlying data type.
 // These are for convenience only - UInt<u32, 15> is still legal
+// Each alias also gets a macro of the same name, e.g. u5!(17), which creates a constant so that the
+// range is checked at compile time. As the macros are defined within a macro, the $ has to be passed in
 macro_rules! type_alias {
-    ($storage:ty, $(($name:ident, $bits:expr)),+) => {
-        $( pub type $name = crate::UInt<$storage, $bits>; )+
+    ($d:tt, $storage:ty, $(($name:ident, $bits:expr)),+) => {
+        $(
+            pub type $name = crate::UInt<$storage, $bits>;
+
+            impl crate::Width for crate::Bits<$bits> {
+                type Storage = $storage;
+                type UInt = $name;
+            }
+
+            #[doc = concat!("Creates a [", stringify!($name), "](type@crate::", stringify!($name), ") from a constant expression, e.g. `",
+                stringify!($name), "!(1)`. Unlike `new`, values that are out of range fail to compile.")]
+            #[macro_export]
+            macro_rules! $name {
+                ($d value:expr) => {{
+                    const VALUE: $d crate::$name = $d crate::$name::new($d value);
+                    VALUE
+                }};
+            }
+        )+
     }
 }
 
 pub use aliases::*;
 
+/// Builds an array of UInt constants, e.g. `uints![u12; 0x000, 0b1010, 4_095]`. Every value is
+/// range checked at compile time. This is meant for tables like register defaults, which
+/// otherwise would be checked at runtime.
+///
+/// ```
+/// # use arbitrary_int::{u5, u12, uints};
+/// const DEFAULTS: [u12; 3] = uints![u12; 0x000, 0b1010, 4_095];
+/// assert_eq!(DEFAULTS[2], u12::new(4_095));
+/// assert_eq!(u5!(31), u5::new(31));
+/// ```
+///
+/// Like the literal macros of the type aliases (e.g. `u5!`), values that are out of range fail
+/// to compile:
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::{u12, uints};
+/// let defaults = uints![u12; 0x000, 4_096];
+/// ```
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::u5;
+/// let value = u5!(40);
+/// ```
+#[macro_export]
+macro_rules! uints {
+    ($type:ty; $($value:expr),* $(,)?) => {
+        [$({
+            const VALUE: $type = <$type>::new($value);
+            VALUE
+        }),*]
+    };
+}
+
 #[allow(non_camel_case_types)]
 #[rustfmt::skip]
 mod aliases {
-    type_alias!(u8, (u1, 1), (u2, 2), (u3, 3), (u4, 4), (u5, 5), (u6, 6), (u7, 7));
-    type_alias!(u16, (u9, 9), (u10, 10), (u11, 11), (u12, 12), (u13, 13), (u14, 14), (u15, 15));
-    type_alias!(u32, (u17, 17), (u18, 18), (u19, 19), (u20, 20), (u21, 21), (u22, 22), (u23, 23), (u24, 24), (u25, 25), (u26, 26), (u27, 27), (u28, 28), (u29, 29), (u30, 30), (u31, 31));
-    type_alias!(u64, (u33, 33), (u34, 34), (u35, 35), (u36, 36), (u37, 37), (u38, 38), (u39, 39), (u40, 40), (u41, 41), (u42, 42), (u43, 43), (u44, 44), (u45, 45), (u46, 46), (u47, 47), (u48, 48), (u49, 49), (u50, 50), (u51, 51), (u52, 52), (u53, 53), (u54, 54), (u55, 55), (u56, 56), (u57, 57), (u58, 58), (u59, 59), (u60, 60), (u61, 61), (u62, 62), (u63, 63));
-    type_alias!(u128, (u65, 65), (u66, 66), (u67, 67), (u68, 68), (u69, 69), (u70, 70), (u71, 71), (u72, 72), (u73, 73), (u74, 74), (u75, 75), (u76, 76), (u77, 77), (u78, 78), (u79, 79), (u80, 80), (u81, 81), (u82, 82), (u83, 83), (u84, 84), (u85, 85), (u86, 86), (u87, 87), (u88, 88), (u89, 89), (u90, 90), (u91, 91), (u92, 92), (u93, 93), (u94, 94), (u95, 95), (u96, 96), (u97, 97), (u98, 98), (u99, 99), (u100, 100), (u101, 101), (u102, 102), (u103, 103), (u104, 104), (u105, 105), (u106, 106), (u107, 107), (u108, 108), (u109, 109), (u110, 110), (u111, 111), (u112, 112), (u113, 113), (u114, 114), (u115, 115), (u116, 116), (u117, 117), (u118, 118), (u119, 119), (u120, 120), (u121, 121), (u122, 122), (u123, 123), (u124, 124), (u125, 125), (u126, 126), (u127, 127));
+    type_alias!($, u8, (u1, 1), (u2, 2), (u3, 3), (u4, 4), (u5, 5), (u6, 6), (u7, 7));
+    type_alias!($, u16, (u9, 9), (u10, 10), (u11, 11), (u12, 12), (u13, 13), (u14, 14), (u15, 15));
+    type_alias!($, u32, (u17, 17), (u18, 18), (u19, 19), (u20, 20), (u21, 21), (u22, 22), (u23, 23), (u24, 24), (u25, 25), (u26, 26), (u27, 27), (u28, 28), (u29, 29), (u30, 30), (u31, 31));
+    type_alias!($, u64, (u33, 33), (u34, 34), (u35, 35), (u36, 36), (u37, 37), (u38, 38), (u39, 39), (u40, 40), (u41, 41), (u42, 42), (u43, 43), (u44, 44), (u45, 45), (u46, 46), (u47, 47), (u48, 48), (u49, 49), (u50, 50), (u51, 51), (u52, 52), (u53, 53), (u54, 54), (u55, 55), (u56, 56), (u57, 57), (u58, 58), (u59, 59), (u60, 60), (u61, 61), (u62, 62), (u63, 63));
+    type_alias!($, u128, (u65, 65), (u66, 66), (u67, 67), (u68, 68), (u69, 69), (u70, 70), (u71, 71), (u72, 72), (u73, 73), (u74, 74), (u75, 75), (u76, 76), (u77, 77), (u78, 78), (u79, 79), (u80, 80), (u81, 81), (u82, 82), (u83, 83), (u84, 84), (u85, 85), (u86, 86), (u87, 87), (u88, 88), (u89, 89), (u90, 90), (u91, 91), (u92, 92), (u93, 93), (u94, 94), (u95, 95), (u96, 96), (u97, 97), (u98, 98), (u99, 99), (u100, 100), (u101, 101), (u102, 102), (u103, 103), (u104, 104), (u105, 105), (u106, 106), (u107, 107), (u108, 108), (u109, 109), (u110, 110), (u111, 111), (u112, 112), (u113, 113), (u114, 114), (u115, 115), (u116, 116), (u117, 117), (u118, 118), (u119, 119), (u120, 120), (u121, 121), (u122, 122), (u123, 123), (u124, 124), (u125, 125), (u126, 126), (u127, 127));
 }
 
 // We need to wrap this in a macro, currently: https://github.com/rust-lang/rust/issues/67792#issuecomment-1130369066
@@ -1559,11 +2238,10 @@ macro_rules! boolu1 {
         impl const From<u1> for bool {
             #[inline]
             fn from(value: u1) -> Self {
-                match value.value() {
+                match_uint!(value, {
                     0 => false,
                     1 => true,
-                    _ => panic!("arbitrary_int_type already validates that this is unreachable"), //TODO: unreachable!() is not const yet
-                }
+                })
             }
         }
     };
@@ -1581,11 +2259,10 @@ macro_rules! boolu1 {
         impl From<u1> for bool {
             #[inline]
             fn from(value: u1) -> Self {
-                match value.value() {
+                match_uint!(value, {
                     0 => false,
                     1 => true,
-                    _ => panic!("arbitrary_int_type already validates that this is unreachable"), //TODO: unreachable!() is not const yet
-                }
+                })
             }
         }
     };
@@ -0,0 +1,87 @@
+use crate::UInt;
+
+// Helpers for match_uint!. They are public as the macro is expanded in other crates, but they
+// aren't meant to be used directly.
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct MatchArms<const COUNT: usize>;
+
+struct Exhaustive<const BITS: usize, const COUNT: usize>;
+
+impl<const BITS: usize, const COUNT: usize> Exhaustive<BITS, COUNT> {
+    const ASSERT: () = {
+        assert!(BITS <= 8, "match_uint! supports UInts with up to 8 bits");
+        assert!(
+            (1 << BITS) == COUNT,
+            "match_uint! requires an arm for every value of the UInt"
+        );
+    };
+}
+
+/// Ensures that every value of 0..values.len() appears exactly once. Returns the number of values
+#[doc(hidden)]
+pub const fn match_arm_count(values: &[u128]) -> usize {
+    assert!(
+        values.len() <= 256,
+        "match_uint! supports UInts with up to 8 bits"
+    );
+    let mut seen = [false; 256];
+    let mut i = 0;
+    while i < values.len() {
+        let value = values[i];
+        // As there are no duplicates, this also guarantees that no value is missing
+        assert!(
+            value < values.len() as u128,
+            "match_uint! requires an arm for every value of the UInt"
+        );
+        assert!(!seen[value as usize], "match_uint! found a duplicate value");
+        seen[value as usize] = true;
+        i += 1;
+    }
+    values.len()
+}
+
+/// Returns the value to match on. Fails to compile if the number of values in the arms doesn't
+/// match the number of values of the UInt
+#[doc(hidden)]
+#[inline]
+#[allow(clippy::let_unit_value)]
+pub const fn match_value<T: Copy, const BITS: usize, const COUNT: usize>(
+    value: UInt<T, BITS>,
+    _arms: MatchArms<COUNT>,
+) -> T {
+    let _ = Exhaustive::<BITS, COUNT>::ASSERT;
+    value.value
+}
+
+/// Matches on every value of a UInt with up to 8 bits, without the need for a catch-all arm:
+///
+/// ```
+/// # use arbitrary_int::{match_uint, u2};
+/// let value = u2::new(2);
+/// let name = match_uint!(value, { 0 => "zero", 1 | 2 => "small", 3 => "large" });
+/// assert_eq!(name, "small");
+/// ```
+///
+/// Each arm consists of one or more literals. Missing or duplicate values as well as values that
+/// are out of range fail to compile. The generated catch-all arm panics, which can only happen if
+/// the value has bits set above its width.
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::{match_uint, u2};
+/// let value = u2::new(2);
+/// // There is no arm for 3
+/// let name = match_uint!(value, { 0 => "zero", 1 | 2 => "small" });
+/// ```
+#[macro_export]
+macro_rules! match_uint {
+    ($value:expr, { $($($pattern:literal)|+ => $arm:expr),+ $(,)? }) => {{
+        const COUNT: usize = $crate::match_arm_count(&[$($($pattern),+),+]);
+        match $crate::match_value($value, $crate::MatchArms::<COUNT>) {
+            $($($pattern)|+ => $arm,)+
+            // It was checked at compile time that the arms cover every possible value
+            _ => unreachable!(),
+        }
+    }};
+}
@@ -0,0 +1,194 @@
+use crate::{Number, UInt};
+
+// Checks that a key with BITS bits is made of DIMENSIONS coordinates with COORDINATE_BITS bits each
+struct MortonWidths<const BITS: usize, const COORDINATE_BITS: usize, const DIMENSIONS: usize> {}
+
+impl<const BITS: usize, const COORDINATE_BITS: usize, const DIMENSIONS: usize>
+    MortonWidths<BITS, COORDINATE_BITS, DIMENSIONS>
+{
+    const VALID: () = {
+        assert!(COORDINATE_BITS > 0, "coordinates need at least one bit");
+        assert!(
+            BITS == DIMENSIONS * COORDINATE_BITS,
+            "BITS has to be the sum of the coordinate widths"
+        );
+    };
+}
+
+// Spreads the lowest 32 bits of x to the even bits
+#[inline]
+const fn spread_2d(x: u64) -> u64 {
+    let x = x & 0x0000_0000_FFFF_FFFF;
+    let x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    let x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    let x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    let x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+// Inverse of spread_2d: Collects the even bits into the lowest 32 bits
+#[inline]
+const fn compact_2d(x: u64) -> u64 {
+    let x = x & 0x5555_5555_5555_5555;
+    let x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    let x = (x | (x >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    let x = (x | (x >> 4)) & 0x00FF_00FF_00FF_00FF;
+    let x = (x | (x >> 8)) & 0x0000_FFFF_0000_FFFF;
+    (x | (x >> 16)) & 0x0000_0000_FFFF_FFFF
+}
+
+// Spreads the lowest 21 bits of x to every third bit
+#[inline]
+const fn spread_3d(x: u64) -> u64 {
+    let x = x & 0x1F_FFFF;
+    let x = (x | (x << 32)) & 0x001F_0000_0000_FFFF;
+    let x = (x | (x << 16)) & 0x001F_0000_FF00_00FF;
+    let x = (x | (x << 8)) & 0x100F_00F0_0F00_F00F;
+    let x = (x | (x << 4)) & 0x10C3_0C30_C30C_30C3;
+    (x | (x << 2)) & 0x1249_2492_4924_9249
+}
+
+// Inverse of spread_3d: Collects every third bit into the lowest 21 bits
+#[inline]
+const fn compact_3d(x: u64) -> u64 {
+    let x = x & 0x1249_2492_4924_9249;
+    let x = (x | (x >> 2)) & 0x10C3_0C30_C30C_30C3;
+    let x = (x | (x >> 4)) & 0x100F_00F0_0F00_F00F;
+    let x = (x | (x >> 8)) & 0x001F_0000_FF00_00FF;
+    let x = (x | (x >> 16)) & 0x001F_0000_0000_FFFF;
+    (x | (x >> 32)) & 0x1F_FFFF
+}
+
+// Keys of up to 64 bits are computed with u64 alone. Wider keys are made of two u64 halves, which
+// hold 32 (2D) or 21 (3D) bits of each coordinate
+#[inline]
+const fn interleave_2d(x: u128, y: u128, coordinate_bits: usize) -> u128 {
+    let low = spread_2d(x as u64) | (spread_2d(y as u64) << 1);
+    if coordinate_bits <= 32 {
+        return low as u128;
+    }
+    let high = spread_2d((x >> 32) as u64) | (spread_2d((y >> 32) as u64) << 1);
+    (low as u128) | ((high as u128) << 64)
+}
+
+#[inline]
+const fn deinterleave_2d(key: u128, coordinate_bits: usize) -> (u128, u128) {
+    let low = key as u64;
+    let x = compact_2d(low) as u128;
+    let y = compact_2d(low >> 1) as u128;
+    if coordinate_bits <= 32 {
+        return (x, y);
+    }
+    let high = (key >> 64) as u64;
+    (
+        x | ((compact_2d(high) as u128) << 32),
+        y | ((compact_2d(high >> 1) as u128) << 32),
+    )
+}
+
+#[inline]
+const fn interleave_3d(x: u128, y: u128, z: u128, coordinate_bits: usize) -> u128 {
+    let low = spread_3d(x as u64) | (spread_3d(y as u64) << 1) | (spread_3d(z as u64) << 2);
+    if coordinate_bits <= 21 {
+        return low as u128;
+    }
+    let high = spread_3d((x >> 21) as u64)
+        | (spread_3d((y >> 21) as u64) << 1)
+        | (spread_3d((z >> 21) as u64) << 2);
+    (low as u128) | ((high as u128) << 63)
+}
+
+#[inline]
+const fn deinterleave_3d(key: u128, coordinate_bits: usize) -> (u128, u128, u128) {
+    let low = key as u64;
+    let x = compact_3d(low) as u128;
+    let y = compact_3d(low >> 1) as u128;
+    let z = compact_3d(low >> 2) as u128;
+    if coordinate_bits <= 21 {
+        return (x, y, z);
+    }
+    let high = (key >> 63) as u64;
+    (
+        x | ((compact_3d(high) as u128) << 21),
+        y | ((compact_3d(high >> 1) as u128) << 21),
+        z | ((compact_3d(high >> 2) as u128) << 21),
+    )
+}
+
+// Const fns can't convert between generic base data types on stable Rust, so every key base data
+// type is paired with one coordinate base data type: The one with half as many bits (u8 for u8)
+macro_rules! morton_impl {
+    ($(($key:ident, $coordinate:ident)),+) => {
+        $(
+            impl<const BITS: usize> UInt<$key, BITS> {
+                /// Interleaves the bits of two coordinates into a Morton (Z-order) key, with the
+                /// lowest bit of x becoming bit 0 and the lowest bit of y becoming bit 1. BITS has to
+                /// be twice the width of a coordinate, otherwise this fails to compile. The
+                /// coordinates use the base data type with half as many bits as the key's (u8 for a
+                /// u8 key), e.g. `UInt::<u32, 32>::interleave_2d(x, y)` for two `UInt<u16, 16>`
+                #[inline]
+                pub const fn interleave_2d<const COORDINATE_BITS: usize>(
+                    x: UInt<$coordinate, COORDINATE_BITS>,
+                    y: UInt<$coordinate, COORDINATE_BITS>,
+                ) -> Self {
+                    let _ = MortonWidths::<BITS, COORDINATE_BITS, 2>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    let key = interleave_2d(x.value as u128, y.value as u128, COORDINATE_BITS);
+                    Self { value: key as $key }
+                }
+
+                /// Splits a Morton (Z-order) key into its two coordinates. This is the inverse of
+                /// [interleave_2d](Self::interleave_2d)
+                #[inline]
+                pub const fn deinterleave_2d<const COORDINATE_BITS: usize>(
+                    self,
+                ) -> (UInt<$coordinate, COORDINATE_BITS>, UInt<$coordinate, COORDINATE_BITS>) {
+                    let _ = MortonWidths::<BITS, COORDINATE_BITS, 2>::VALID;
+                    let (x, y) = deinterleave_2d(self.value as u128, COORDINATE_BITS);
+                    (UInt { value: x as $coordinate }, UInt { value: y as $coordinate })
+                }
+
+                /// Interleaves the bits of three coordinates into a Morton (Z-order) key, with the
+                /// lowest bits of x, y and z becoming bits 0, 1 and 2. BITS has to be three times the
+                /// width of a coordinate, otherwise this fails to compile. The coordinates use the
+                /// base data type with half as many bits as the key's (u8 for a u8 key), e.g.
+                /// `u63::interleave_3d(x, y, z)` for three `u21`. Narrower coordinates have to be
+                /// converted first: A `u24` key takes three `UInt<u16, 8>`, not three `u8`
+                #[inline]
+                pub const fn interleave_3d<const COORDINATE_BITS: usize>(
+                    x: UInt<$coordinate, COORDINATE_BITS>,
+                    y: UInt<$coordinate, COORDINATE_BITS>,
+                    z: UInt<$coordinate, COORDINATE_BITS>,
+                ) -> Self {
+                    let _ = MortonWidths::<BITS, COORDINATE_BITS, 3>::VALID;
+                    // Query MAX to ensure we get a compiler error if the current definition is bogus (e.g. <u8, 9>)
+                    let _ = Self::MAX;
+                    let key = interleave_3d(x.value as u128, y.value as u128, z.value as u128, COORDINATE_BITS);
+                    Self { value: key as $key }
+                }
+
+                /// Splits a Morton (Z-order) key into its three coordinates. This is the inverse of
+                /// [interleave_3d](Self::interleave_3d)
+                #[inline]
+                pub const fn deinterleave_3d<const COORDINATE_BITS: usize>(
+                    self,
+                ) -> (
+                    UInt<$coordinate, COORDINATE_BITS>,
+                    UInt<$coordinate, COORDINATE_BITS>,
+                    UInt<$coordinate, COORDINATE_BITS>,
+                ) {
+                    let _ = MortonWidths::<BITS, COORDINATE_BITS, 3>::VALID;
+                    let (x, y, z) = deinterleave_3d(self.value as u128, COORDINATE_BITS);
+                    (
+                        UInt { value: x as $coordinate },
+                        UInt { value: y as $coordinate },
+                        UInt { value: z as $coordinate },
+                    )
+                }
+            }
+        )+
+    };
+}
+
+morton_impl!((u8, u8), (u16, u8), (u32, u16), (u64, u32), (u128, u64));
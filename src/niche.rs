@@ -0,0 +1,396 @@
+use crate::{Bits, Number, UInt, UIntFor, Width};
+use core::cmp::Ordering;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::hash::{Hash, Hasher};
+
+/// Maps a [Bits] to the niche-optimized representation of a [NicheUInt]. Implemented for all bit
+/// counts from 1 to 127 that aren't a multiple of 8 (for those, every bit pattern is valid, so
+/// there is no niche).
+pub trait NicheWidth: Width {
+    type Repr: Copy + Eq + Hash;
+
+    fn pack(value: Self::UInt) -> Self::Repr;
+
+    fn unpack(repr: Self::Repr) -> Self::UInt;
+}
+
+/// An integer with the same range of values as a `UInt<_, BITS>`, but with a layout that makes
+/// the unused values of the topmost byte available as a niche. This means that for example
+/// `Option<NicheUInt<7>>` takes one byte and `Option<NicheUInt<31>>` takes four (compared to two
+/// and eight bytes for `Option<u7>` and `Option<u31>`). This makes it a good fit for tables,
+/// while `UInt` remains the better choice for calculations.
+///
+/// Internally, the value is stored as little endian bytes followed by an enum that holds the
+/// remaining topmost bits. As a result, NicheUInt has an alignment of 1.
+pub struct NicheUInt<const BITS: usize>
+where
+    Bits<BITS>: NicheWidth,
+{
+    repr: <Bits<BITS> as NicheWidth>::Repr,
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NicheRepr<const LOW_BYTES: usize, Top> {
+    low: [u8; LOW_BYTES],
+    top: Top,
+}
+
+/// The 1 topmost bits of a [NicheUInt]. Having exactly 2 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top1 { V0 = 0, V1 }
+
+/// The 2 topmost bits of a [NicheUInt]. Having exactly 4 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top2 { V0 = 0, V1, V2, V3 }
+
+/// The 3 topmost bits of a [NicheUInt]. Having exactly 8 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top3 { V0 = 0, V1, V2, V3, V4, V5, V6, V7 }
+
+/// The 4 topmost bits of a [NicheUInt]. Having exactly 16 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top4 { V0 = 0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15 }
+
+/// The 5 topmost bits of a [NicheUInt]. Having exactly 32 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top5 { V0 = 0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15, V16, V17, V18, V19, V20, V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31 }
+
+/// The 6 topmost bits of a [NicheUInt]. Having exactly 64 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top6 { V0 = 0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15, V16, V17, V18, V19, V20, V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31, V32, V33, V34, V35, V36, V37, V38, V39, V40, V41, V42, V43, V44, V45, V46, V47, V48, V49, V50, V51, V52, V53, V54, V55, V56, V57, V58, V59, V60, V61, V62, V63 }
+
+/// The 7 topmost bits of a [NicheUInt]. Having exactly 128 variants, the remaining values of the
+/// byte are a niche
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[rustfmt::skip]
+pub enum Top7 { V0 = 0, V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12, V13, V14, V15, V16, V17, V18, V19, V20, V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31, V32, V33, V34, V35, V36, V37, V38, V39, V40, V41, V42, V43, V44, V45, V46, V47, V48, V49, V50, V51, V52, V53, V54, V55, V56, V57, V58, V59, V60, V61, V62, V63, V64, V65, V66, V67, V68, V69, V70, V71, V72, V73, V74, V75, V76, V77, V78, V79, V80, V81, V82, V83, V84, V85, V86, V87, V88, V89, V90, V91, V92, V93, V94, V95, V96, V97, V98, V99, V100, V101, V102, V103, V104, V105, V106, V107, V108, V109, V110, V111, V112, V113, V114, V115, V116, V117, V118, V119, V120, V121, V122, V123, V124, V125, V126, V127 }
+
+macro_rules! niche_uint_impl {
+    ($type:ident, [$(($bits:expr, $low_bytes:expr, $top:ident)),+]) => {
+        $(
+            impl NicheWidth for Bits<$bits> {
+                type Repr = NicheRepr<$low_bytes, $top>;
+
+                #[inline]
+                fn pack(value: UInt<$type, $bits>) -> Self::Repr {
+                    NicheUInt::<$bits>::new(value).repr
+                }
+
+                #[inline]
+                fn unpack(repr: Self::Repr) -> UInt<$type, $bits> {
+                    NicheUInt::<$bits> { repr }.get()
+                }
+            }
+
+            impl NicheUInt<$bits> {
+                pub const MIN: Self = Self::new(<UInt<$type, $bits> as Number>::MIN);
+
+                pub const MAX: Self = Self::new(<UInt<$type, $bits> as Number>::MAX);
+
+                /// Converts a UInt into its niche-optimized representation
+                #[inline]
+                pub const fn new(value: UInt<$type, $bits>) -> Self {
+                    // Work with u128 so that the shifts never overflow, no matter the base data type
+                    let value = value.value as u128;
+                    let mut low = [0u8; $low_bytes];
+                    let mut i = 0;
+                    while i < low.len() {
+                        low[i] = (value >> (i << 3)) as u8;
+                        i += 1;
+                    }
+                    // Mask the topmost bits instead of relying on value being at most MAX, so that
+                    // a UInt that breaks its invariant can't produce an invalid enum
+                    const TOP_MASK: u8 = (1 << ($bits - ($low_bytes << 3))) - 1;
+                    let top_bits = (value >> ($low_bytes << 3)) as u8 & TOP_MASK;
+                    // Safety: The TopN enums are repr(u8) and declare one variant for each value
+                    // from 0 to 2^N - 1, where N is the number of bits above the low bytes.
+                    // top_bits was masked to N bits, so it always matches a declared variant
+                    let top = unsafe { core::mem::transmute::<u8, $top>(top_bits) };
+                    Self { repr: NicheRepr { low, top } }
+                }
+
+                /// Returns the value as a UInt
+                #[inline]
+                pub const fn get(self) -> UInt<$type, $bits> {
+                    let mut value = self.repr.top as u8 as u128;
+                    let mut i = self.repr.low.len();
+                    while i > 0 {
+                        i -= 1;
+                        value = (value << 8) | self.repr.low[i] as u128;
+                    }
+                    UInt { value: value as $type }
+                }
+            }
+
+            impl From<UInt<$type, $bits>> for NicheUInt<$bits> {
+                #[inline]
+                fn from(value: UInt<$type, $bits>) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl From<NicheUInt<$bits>> for UInt<$type, $bits> {
+                #[inline]
+                fn from(value: NicheUInt<$bits>) -> Self {
+                    value.get()
+                }
+            }
+
+            // This is the whole point of this type, so ensure it at compile time
+            const _: () = assert!(
+                core::mem::size_of::<Option<NicheUInt<$bits>>>()
+                    == core::mem::size_of::<NicheUInt<$bits>>()
+            );
+        )+
+    };
+}
+
+niche_uint_impl!(
+    u8,
+    [
+        (1, 0, Top1),
+        (2, 0, Top2),
+        (3, 0, Top3),
+        (4, 0, Top4),
+        (5, 0, Top5),
+        (6, 0, Top6),
+        (7, 0, Top7)
+    ]
+);
+niche_uint_impl!(
+    u16,
+    [
+        (9, 1, Top1),
+        (10, 1, Top2),
+        (11, 1, Top3),
+        (12, 1, Top4),
+        (13, 1, Top5),
+        (14, 1, Top6),
+        (15, 1, Top7)
+    ]
+);
+niche_uint_impl!(
+    u32,
+    [
+        (17, 2, Top1),
+        (18, 2, Top2),
+        (19, 2, Top3),
+        (20, 2, Top4),
+        (21, 2, Top5),
+        (22, 2, Top6),
+        (23, 2, Top7),
+        (25, 3, Top1),
+        (26, 3, Top2),
+        (27, 3, Top3),
+        (28, 3, Top4),
+        (29, 3, Top5),
+        (30, 3, Top6),
+        (31, 3, Top7)
+    ]
+);
+niche_uint_impl!(
+    u64,
+    [
+        (33, 4, Top1),
+        (34, 4, Top2),
+        (35, 4, Top3),
+        (36, 4, Top4),
+        (37, 4, Top5),
+        (38, 4, Top6),
+        (39, 4, Top7),
+        (41, 5, Top1),
+        (42, 5, Top2),
+        (43, 5, Top3),
+        (44, 5, Top4),
+        (45, 5, Top5),
+        (46, 5, Top6),
+        (47, 5, Top7),
+        (49, 6, Top1),
+        (50, 6, Top2),
+        (51, 6, Top3),
+        (52, 6, Top4),
+        (53, 6, Top5),
+        (54, 6, Top6),
+        (55, 6, Top7),
+        (57, 7, Top1),
+        (58, 7, Top2),
+        (59, 7, Top3),
+        (60, 7, Top4),
+        (61, 7, Top5),
+        (62, 7, Top6),
+        (63, 7, Top7)
+    ]
+);
+niche_uint_impl!(
+    u128,
+    [
+        (65, 8, Top1),
+        (66, 8, Top2),
+        (67, 8, Top3),
+        (68, 8, Top4),
+        (69, 8, Top5),
+        (70, 8, Top6),
+        (71, 8, Top7),
+        (73, 9, Top1),
+        (74, 9, Top2),
+        (75, 9, Top3),
+        (76, 9, Top4),
+        (77, 9, Top5),
+        (78, 9, Top6),
+        (79, 9, Top7),
+        (81, 10, Top1),
+        (82, 10, Top2),
+        (83, 10, Top3),
+        (84, 10, Top4),
+        (85, 10, Top5),
+        (86, 10, Top6),
+        (87, 10, Top7),
+        (89, 11, Top1),
+        (90, 11, Top2),
+        (91, 11, Top3),
+        (92, 11, Top4),
+        (93, 11, Top5),
+        (94, 11, Top6),
+        (95, 11, Top7),
+        (97, 12, Top1),
+        (98, 12, Top2),
+        (99, 12, Top3),
+        (100, 12, Top4),
+        (101, 12, Top5),
+        (102, 12, Top6),
+        (103, 12, Top7),
+        (105, 13, Top1),
+        (106, 13, Top2),
+        (107, 13, Top3),
+        (108, 13, Top4),
+        (109, 13, Top5),
+        (110, 13, Top6),
+        (111, 13, Top7),
+        (113, 14, Top1),
+        (114, 14, Top2),
+        (115, 14, Top3),
+        (116, 14, Top4),
+        (117, 14, Top5),
+        (118, 14, Top6),
+        (119, 14, Top7),
+        (121, 15, Top1),
+        (122, 15, Top2),
+        (123, 15, Top3),
+        (124, 15, Top4),
+        (125, 15, Top5),
+        (126, 15, Top6),
+        (127, 15, Top7)
+    ]
+);
+
+impl<const BITS: usize> Clone for NicheUInt<BITS>
+where
+    Bits<BITS>: NicheWidth,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const BITS: usize> Copy for NicheUInt<BITS> where Bits<BITS>: NicheWidth {}
+
+impl<const BITS: usize> PartialEq for NicheUInt<BITS>
+where
+    Bits<BITS>: NicheWidth,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<const BITS: usize> Eq for NicheUInt<BITS> where Bits<BITS>: NicheWidth {}
+
+impl<const BITS: usize> Hash for NicheUInt<BITS>
+where
+    Bits<BITS>: NicheWidth,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.repr.hash(state)
+    }
+}
+
+// The bytes are stored little endian, so the derived order of the representation would be wrong.
+// Compare the actual values instead
+impl<const BITS: usize> PartialOrd for NicheUInt<BITS>
+where
+    Bits<BITS>: NicheWidth,
+    UIntFor<BITS>: Ord,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const BITS: usize> Ord for NicheUInt<BITS>
+where
+    Bits<BITS>: NicheWidth,
+    UIntFor<BITS>: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let this = <Bits<BITS> as NicheWidth>::unpack(self.repr);
+        let other = <Bits<BITS> as NicheWidth>::unpack(other.repr);
+        this.cmp(&other)
+    }
+}
+
+impl<const BITS: usize> Default for NicheUInt<BITS>
+where
+    Bits<BITS>: NicheWidth,
+    UIntFor<BITS>: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            repr: <Bits<BITS> as NicheWidth>::pack(Default::default()),
+        }
+    }
+}
+
+macro_rules! niche_uint_fmt_impl {
+    ($($trait:ident),+) => {
+        $(
+            impl<const BITS: usize> $trait for NicheUInt<BITS>
+            where
+                Bits<BITS>: NicheWidth,
+                UIntFor<BITS>: $trait,
+            {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    <Bits<BITS> as NicheWidth>::unpack(self.repr).fmt(f)
+                }
+            }
+        )+
+    };
+}
+
+niche_uint_fmt_impl!(Display, Debug, LowerHex, UpperHex, Octal, Binary);
@@ -0,0 +1,191 @@
+use crate::{Number, TryNewError, UInt};
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::hash::Hash;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::{Div, DivAssign};
+
+/// Maps a base data type (e.g. `u32`) to its non-zero counterpart in `core::num` (e.g. `NonZeroU32`)
+pub trait NonZeroStorage {
+    type NonZero: Copy + Eq + Ord + Hash + Debug;
+}
+
+macro_rules! non_zero_storage_impl {
+    ($(($type:ident, $non_zero:ident)),+) => {
+        $(
+            impl NonZeroStorage for $type {
+                type NonZero = $non_zero;
+            }
+        )+
+    };
+}
+
+non_zero_storage_impl!(
+    (u8, NonZeroU8),
+    (u16, NonZeroU16),
+    (u32, NonZeroU32),
+    (u64, NonZeroU64),
+    (u128, NonZeroU128),
+    (usize, NonZeroUsize)
+);
+
+/// An integer with an arbitrary number of bits that is known to not equal zero, the counterpart of
+/// `NonZeroU8` and friends. Like those, `Option<NonZeroUInt<T, BITS>>` has the same size as `T`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct NonZeroUInt<T: NonZeroStorage, const BITS: usize> {
+    value: T::NonZero,
+}
+
+macro_rules! non_zero_uint_impl {
+    ($(($type:ident, $non_zero:ident)),+) => {
+        $(
+            impl<const BITS: usize> NonZeroUInt<$type, BITS> {
+                pub const MIN: Self = match Self::new(UInt::<$type, BITS> { value: 1 }) {
+                    Some(result) => result,
+                    None => unreachable!(),
+                };
+
+                pub const MAX: Self = match Self::new(<UInt<$type, BITS> as Number>::MAX) {
+                    Some(result) => result,
+                    None => unreachable!(),
+                };
+
+                /// Creates a non-zero integer if the given value is not zero
+                #[inline]
+                pub const fn new(value: UInt<$type, BITS>) -> Option<Self> {
+                    match $non_zero::new(value.value) {
+                        Some(value) => Some(Self { value }),
+                        None => None,
+                    }
+                }
+
+                /// Creates a non-zero integer without checking whether the value is zero
+                ///
+                /// # Safety
+                /// The value must not be zero.
+                #[inline]
+                pub const unsafe fn new_unchecked(value: UInt<$type, BITS>) -> Self {
+                    Self {
+                        value: $non_zero::new_unchecked(value.value),
+                    }
+                }
+
+                /// Returns the value as a UInt
+                #[inline]
+                pub const fn get(self) -> UInt<$type, BITS> {
+                    UInt {
+                        value: self.value.get(),
+                    }
+                }
+
+                /// Adds an integer, returning `None` on overflow. The result can't be zero
+                pub const fn checked_add(self, other: UInt<$type, BITS>) -> Option<Self> {
+                    match self.get().checked_add(other) {
+                        // Safety: The sum of a non-zero value and an unsigned value can't be zero
+                        Some(sum) => Some(unsafe { Self::new_unchecked(sum) }),
+                        None => None,
+                    }
+                }
+
+                /// Multiplies two non-zero integers, returning `None` on overflow. The result can't be zero
+                pub const fn checked_mul(self, other: Self) -> Option<Self> {
+                    match self.get().checked_mul(other.get()) {
+                        // Safety: Without overflow, the product of two non-zero values can't be zero
+                        Some(product) => Some(unsafe { Self::new_unchecked(product) }),
+                        None => None,
+                    }
+                }
+
+                /// Raises to the power of exp, returning `None` on overflow. The result can't be zero
+                pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+                    match self.get().value.checked_pow(exp) {
+                        // Safety: Without overflow, a power of a non-zero value can't be zero
+                        Some(power) if power <= UInt::<$type, BITS>::MASK => {
+                            Some(unsafe { Self::new_unchecked(UInt { value: power }) })
+                        }
+                        _ => None,
+                    }
+                }
+
+                /// Adds an integer, saturating at MAX
+                pub const fn saturating_add(self, other: UInt<$type, BITS>) -> Self {
+                    // Safety: The sum of a non-zero value and an unsigned value can't be zero
+                    unsafe { Self::new_unchecked(self.get().saturating_add(other)) }
+                }
+
+                /// Multiplies two non-zero integers, saturating at MAX
+                pub const fn saturating_mul(self, other: Self) -> Self {
+                    // Safety: The product of two non-zero values is either correct or MAX, neither
+                    // of which is zero
+                    unsafe { Self::new_unchecked(self.get().saturating_mul(other.get())) }
+                }
+
+                /// Raises to the power of exp, saturating at MAX
+                pub const fn saturating_pow(self, exp: u32) -> Self {
+                    // Safety: Powers of a non-zero value are either correct or MAX, neither of
+                    // which is zero
+                    unsafe { Self::new_unchecked(self.get().saturating_pow(exp)) }
+                }
+            }
+
+            impl<const BITS: usize> From<NonZeroUInt<$type, BITS>> for UInt<$type, BITS> {
+                #[inline]
+                fn from(value: NonZeroUInt<$type, BITS>) -> Self {
+                    value.get()
+                }
+            }
+
+            impl<const BITS: usize> TryFrom<UInt<$type, BITS>> for NonZeroUInt<$type, BITS> {
+                type Error = TryNewError;
+
+                #[inline]
+                fn try_from(value: UInt<$type, BITS>) -> Result<Self, Self::Error> {
+                    Self::new(value).ok_or(TryNewError {})
+                }
+            }
+
+            // Division by a non-zero value can't panic
+            impl<const BITS: usize> Div<NonZeroUInt<$type, BITS>> for UInt<$type, BITS> {
+                type Output = UInt<$type, BITS>;
+
+                #[inline]
+                fn div(self, rhs: NonZeroUInt<$type, BITS>) -> Self::Output {
+                    UInt {
+                        value: self.value / rhs.value,
+                    }
+                }
+            }
+
+            impl<const BITS: usize> DivAssign<NonZeroUInt<$type, BITS>> for UInt<$type, BITS> {
+                #[inline]
+                fn div_assign(&mut self, rhs: NonZeroUInt<$type, BITS>) {
+                    self.value = self.value / rhs.value;
+                }
+            }
+
+            non_zero_uint_fmt_impl!($type, [Display, Debug, LowerHex, UpperHex, Octal, Binary]);
+        )+
+    };
+}
+
+macro_rules! non_zero_uint_fmt_impl {
+    ($type:ident, [$($trait:ident),+]) => {
+        $(
+            impl<const BITS: usize> $trait for NonZeroUInt<$type, BITS> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    $trait::fmt(&self.value.get(), f)
+                }
+            }
+        )+
+    };
+}
+
+non_zero_uint_impl!(
+    (u8, NonZeroU8),
+    (u16, NonZeroU16),
+    (u32, NonZeroU32),
+    (u64, NonZeroU64),
+    (u128, NonZeroU128),
+    (usize, NonZeroUsize)
+);
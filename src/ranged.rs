@@ -0,0 +1,201 @@
+use crate::nonzero::NonZeroStorage;
+use crate::{Number, TryNewError, UInt};
+use core::fmt::{Debug, Display, Formatter};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+/// An integer that is restricted to `MIN..=MAX`, e.g. `Ranged<u4, 1, 12>` for months. `T` is the
+/// UInt that contains the range.
+///
+/// `T` has to be the smallest UInt that holds MAX: It has exactly as many bits as MAX needs and
+/// the smallest base data type for that number of bits. Deriving it from MAX automatically would
+/// require generic const expressions, which aren't available on stable Rust, so it has to be
+/// spelled out. A `T` that isn't the smallest fails to compile:
+///
+/// ```compile_fail,E0080
+/// # use arbitrary_int::{u5, Ranged};
+/// // 12 fits into a u4
+/// let month = Ranged::<u5, 1, 12>::new(u5::new(3));
+/// ```
+///
+/// Internally, the distance to MIN plus one is stored in the non-zero type of the base data type
+/// (e.g. `NonZeroU8` for `u4`). This means that `Option<Ranged<..>>` is the same size as
+/// `Ranged<..>`. As there has to be a value left over for this, the range can't span all values of
+/// the base data type (e.g. 0..=255 for `UInt<u8, 8>`).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Ranged<T, const MIN: u128, const MAX: u128>
+where
+    T: Number,
+    T::UnderlyingType: NonZeroStorage,
+{
+    offset: <T::UnderlyingType as NonZeroStorage>::NonZero,
+}
+
+macro_rules! ranged_impl {
+    ($(($type:ident, $non_zero:ident)),+) => {
+        $(
+            impl<const BITS: usize, const MIN: u128, const MAX: u128> Ranged<UInt<$type, BITS>, MIN, MAX> {
+                // Validates the bounds. This is queried by every constructor, so that invalid
+                // definitions fail to compile
+                const VALID: () = {
+                    assert!(MIN <= MAX);
+                    assert!(MAX <= UInt::<$type, BITS>::MAX.value as u128);
+                    // T has to be the smallest UInt that holds MAX, both in bits and in base data
+                    // type
+                    let max_bits = if MAX == 0 {
+                        1
+                    } else {
+                        (u128::BITS - MAX.leading_zeros()) as usize
+                    };
+                    assert!(BITS == max_bits, "T has to have exactly as many bits as MAX needs");
+                    let storage_bits = if BITS <= 8 { 8 } else { BITS.next_power_of_two() };
+                    assert!(
+                        <$type>::BITS as usize == storage_bits,
+                        "T has to use the smallest base data type for its number of bits"
+                    );
+                    assert!(MAX - MIN < <$type>::MAX as u128);
+                };
+
+                pub const MIN: Self = Self::new(UInt::<$type, BITS> { value: MIN as $type });
+
+                pub const MAX: Self = Self::new(UInt::<$type, BITS> { value: MAX as $type });
+
+                /// Creates an instance. Panics if the given value is outside of MIN..=MAX
+                #[inline]
+                pub const fn new(value: UInt<$type, BITS>) -> Self {
+                    match Self::try_new(value) {
+                        Ok(result) => result,
+                        Err(_) => panic!("value is outside of the valid range"),
+                    }
+                }
+
+                /// Creates an instance or an error if the given value is outside of MIN..=MAX
+                #[inline]
+                pub const fn try_new(value: UInt<$type, BITS>) -> Result<Self, TryNewError> {
+                    let _ = Self::VALID;
+                    let value = value.value;
+                    if (value as u128) < MIN || (value as u128) > MAX {
+                        return Err(TryNewError {});
+                    }
+                    // Safety: value >= MIN, so the result is at least 1. VALID ensures that it
+                    // doesn't overflow
+                    let offset = unsafe { $non_zero::new_unchecked(value - MIN as $type + 1) };
+                    Ok(Self { offset })
+                }
+
+                /// Returns the value as the containing UInt
+                #[inline]
+                pub const fn get(self) -> UInt<$type, BITS> {
+                    UInt {
+                        value: self.offset.get() - 1 + MIN as $type,
+                    }
+                }
+
+                /// Returns the type as a fundamental data type
+                #[inline]
+                pub const fn value(self) -> $type {
+                    self.get().value
+                }
+
+                /// Adds, returning `None` if the result is outside of MIN..=MAX
+                pub const fn checked_add(self, rhs: UInt<$type, BITS>) -> Option<Self> {
+                    match self.get().checked_add(rhs) {
+                        Some(sum) => match Self::try_new(sum) {
+                            Ok(result) => Some(result),
+                            Err(_) => None,
+                        },
+                        None => None,
+                    }
+                }
+
+                /// Subtracts, returning `None` if the result is outside of MIN..=MAX
+                pub const fn checked_sub(self, rhs: UInt<$type, BITS>) -> Option<Self> {
+                    match self.get().checked_sub(rhs) {
+                        Some(difference) => match Self::try_new(difference) {
+                            Ok(result) => Some(result),
+                            Err(_) => None,
+                        },
+                        None => None,
+                    }
+                }
+
+                /// Multiplies, returning `None` if the result is outside of MIN..=MAX
+                pub const fn checked_mul(self, rhs: UInt<$type, BITS>) -> Option<Self> {
+                    match self.get().checked_mul(rhs) {
+                        Some(product) => match Self::try_new(product) {
+                            Ok(result) => Some(result),
+                            Err(_) => None,
+                        },
+                        None => None,
+                    }
+                }
+
+                /// Adds, saturating at MAX
+                pub const fn saturating_add(self, rhs: UInt<$type, BITS>) -> Self {
+                    match self.checked_add(rhs) {
+                        Some(result) => result,
+                        None => Self::MAX,
+                    }
+                }
+
+                /// Subtracts, saturating at MIN
+                pub const fn saturating_sub(self, rhs: UInt<$type, BITS>) -> Self {
+                    match self.checked_sub(rhs) {
+                        Some(result) => result,
+                        None => Self::MIN,
+                    }
+                }
+
+                /// Multiplies, saturating at MIN (when multiplying by zero) or MAX
+                pub const fn saturating_mul(self, rhs: UInt<$type, BITS>) -> Self {
+                    let product = self.get().saturating_mul(rhs);
+                    if (product.value as u128) < MIN {
+                        Self::MIN
+                    } else if (product.value as u128) > MAX {
+                        Self::MAX
+                    } else {
+                        Self::new(product)
+                    }
+                }
+            }
+
+            impl<const BITS: usize, const MIN: u128, const MAX: u128> From<Ranged<UInt<$type, BITS>, MIN, MAX>> for UInt<$type, BITS> {
+                #[inline]
+                fn from(value: Ranged<UInt<$type, BITS>, MIN, MAX>) -> Self {
+                    value.get()
+                }
+            }
+
+            impl<const BITS: usize, const MIN: u128, const MAX: u128> TryFrom<UInt<$type, BITS>> for Ranged<UInt<$type, BITS>, MIN, MAX> {
+                type Error = TryNewError;
+
+                #[inline]
+                fn try_from(value: UInt<$type, BITS>) -> Result<Self, Self::Error> {
+                    Self::try_new(value)
+                }
+            }
+
+            impl<const BITS: usize, const MIN: u128, const MAX: u128> Display for Ranged<UInt<$type, BITS>, MIN, MAX> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    Display::fmt(&self.value(), f)
+                }
+            }
+
+            impl<const BITS: usize, const MIN: u128, const MAX: u128> Debug for Ranged<UInt<$type, BITS>, MIN, MAX> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    Debug::fmt(&self.value(), f)
+                }
+            }
+        )+
+    };
+}
+
+ranged_impl!(
+    (u8, NonZeroU8),
+    (u16, NonZeroU16),
+    (u32, NonZeroU32),
+    (u64, NonZeroU64),
+    (u128, NonZeroU128),
+    (usize, NonZeroUsize)
+);
@@ -0,0 +1,127 @@
+//! Typed access to memory-mapped (MMIO) registers. A [Register] performs volatile reads and writes
+//! and allows reading and writing `UInt` fields at a given bit offset, similar to `extract_u32`.
+
+use crate::{Number, UnsignedNumber};
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+/// Marker for registers that can only be read
+#[derive(Debug, Copy, Clone)]
+pub struct ReadOnly;
+
+/// Marker for registers that can only be written
+#[derive(Debug, Copy, Clone)]
+pub struct WriteOnly;
+
+/// Marker for registers that can be read and written
+#[derive(Debug, Copy, Clone)]
+pub struct ReadWrite;
+
+/// Access types that allow reading
+pub trait Readable {}
+
+/// Access types that allow writing
+pub trait Writable {}
+
+impl Readable for ReadOnly {}
+impl Readable for ReadWrite {}
+impl Writable for WriteOnly {}
+impl Writable for ReadWrite {}
+
+/// A register of type `T` (e.g. `u32`) with the access type `A` ([ReadOnly], [WriteOnly] or
+/// [ReadWrite]). All accesses are volatile.
+pub struct Register<T, A> {
+    ptr: *mut T,
+    access: PhantomData<A>,
+}
+
+impl<T, A> Register<T, A> {
+    /// Creates a register at the given address
+    ///
+    /// # Safety
+    /// `ptr` must be properly aligned and valid for volatile reads and/or writes (as allowed by `A`)
+    /// for as long as the register is being used.
+    #[inline]
+    pub const unsafe fn new(ptr: *mut T) -> Self {
+        Self {
+            ptr,
+            access: PhantomData,
+        }
+    }
+
+    /// Returns the address of the register
+    #[inline]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.ptr
+    }
+}
+
+// Returns a mask with the lowest bit_count bits set. Also ensures that the field fits
+#[inline]
+fn field_mask<T: UnsignedNumber>(start_bit: usize, bit_count: usize) -> T {
+    assert!(start_bit + bit_count <= T::BITS);
+    T::MAX >> ((T::BITS - bit_count) as u32)
+}
+
+impl<T: UnsignedNumber, A: Readable> Register<T, A> {
+    /// Performs a volatile read of the whole register
+    #[inline]
+    pub fn read(&self) -> T {
+        // Safety: The caller of new() guaranteed that the pointer is valid
+        unsafe { self.ptr.read_volatile() }
+    }
+
+    /// Reads the register and extracts the field that starts at start_bit. This is equivalent to
+    /// `F::extract_u32(register.read(), start_bit)` for a u32 register. Panics if the field doesn't
+    /// fit within the register
+    #[inline]
+    pub fn read_field<F>(&self, start_bit: usize) -> F
+    where
+        F: Number,
+        F::UnderlyingType: TryFrom<T>,
+    {
+        let mask = field_mask::<T>(start_bit, F::BITS);
+        let raw = (self.read() >> start_bit as u32) & mask;
+        match F::UnderlyingType::try_from(raw) {
+            Ok(value) => F::new(value),
+            Err(_) => unreachable!("value was masked to the number of bits of the field"),
+        }
+    }
+}
+
+impl<T: UnsignedNumber, A: Writable> Register<T, A> {
+    /// Performs a volatile write of the whole register
+    #[inline]
+    pub fn write(&self, value: T) {
+        // Safety: The caller of new() guaranteed that the pointer is valid
+        unsafe { self.ptr.write_volatile(value) }
+    }
+}
+
+impl<T: UnsignedNumber> Register<T, ReadWrite> {
+    /// Reads the register, passes the value to f and writes the result back
+    #[inline]
+    pub fn modify<F: FnOnce(T) -> T>(&self, f: F) {
+        self.write(f(self.read()));
+    }
+
+    /// Replaces the field that starts at start_bit with the given value, leaving all other bits
+    /// unchanged. This is a (non-atomic) read-modify-write. Panics if the field doesn't fit within
+    /// the register
+    #[inline]
+    pub fn modify_field<F>(&self, start_bit: usize, value: F)
+    where
+        F: Number,
+        T: From<F>,
+    {
+        let mask = field_mask::<T>(start_bit, F::BITS) << start_bit as u32;
+        let bits = T::from(value) << start_bit as u32;
+        self.modify(|current| (current & !mask) | bits);
+    }
+}
+
+impl<T, A> Debug for Register<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Register").field("ptr", &self.ptr).finish()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::UnsignedNumber;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Sub, SubAssign,
+};
+
+/// Provides intentionally-saturating arithmetic on `T`, like `core::num::Saturating`. Unlike the
+/// one in core, this works for every `UInt` (and native type), so a `Saturating<u12>` stops at 4095.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+macro_rules! saturating_binop_impl {
+    ($($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $saturating_fn:ident);+) => {
+        $(
+            impl<T: UnsignedNumber> $trait for Saturating<T> {
+                type Output = Saturating<T>;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    Saturating(self.0.$saturating_fn(rhs.0))
+                }
+            }
+
+            impl<T: UnsignedNumber> $assign_trait for Saturating<T> {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = Saturating(self.0.$saturating_fn(rhs.0));
+                }
+            }
+        )+
+    };
+}
+
+saturating_binop_impl!(
+    Add, add, AddAssign, add_assign, saturating_add;
+    Sub, sub, SubAssign, sub_assign, saturating_sub;
+    Mul, mul, MulAssign, mul_assign, saturating_mul;
+    Div, div, DivAssign, div_assign, saturating_div
+);
+
+// Bit operations can't overflow, so they simply forward
+macro_rules! saturating_bitop_impl {
+    ($($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident);+) => {
+        $(
+            impl<T: UnsignedNumber> $trait for Saturating<T> {
+                type Output = Saturating<T>;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    Saturating(self.0.$fn(rhs.0))
+                }
+            }
+
+            impl<T: UnsignedNumber> $assign_trait for Saturating<T> {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    self.0.$assign_fn(rhs.0);
+                }
+            }
+        )+
+    };
+}
+
+saturating_bitop_impl!(
+    BitAnd, bitand, BitAndAssign, bitand_assign;
+    BitOr, bitor, BitOrAssign, bitor_assign;
+    BitXor, bitxor, BitXorAssign, bitxor_assign
+);
+
+impl<T: UnsignedNumber> Not for Saturating<T> {
+    type Output = Saturating<T>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        Saturating(!self.0)
+    }
+}
+
+macro_rules! saturating_fmt_impl {
+    ($($trait:ident),+) => {
+        $(
+            impl<T: $trait> $trait for Saturating<T> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+        )+
+    };
+}
+
+saturating_fmt_impl!(Display, Debug, LowerHex, UpperHex, Octal, Binary);
@@ -0,0 +1,74 @@
+use crate::UInt;
+use core::cmp::Ordering;
+
+// Serial number arithmetic as defined in RFC 1982. Sequence numbers wrap around, so the derived Ord
+// gives the wrong answer across the wrap (e.g. 4095 is "before" 0 for a u12 counter). Serial number
+// arithmetic instead considers whichever direction is shorter.
+macro_rules! serial_impl {
+    ($(($type:ident, $signed:ident, $sign_extend_fn:ident)),+) => {
+        $(
+            impl<const BITS: usize> UInt<$type, BITS> {
+                /// Half of the number space. Serial numbers that are exactly this far apart can't be
+                /// compared
+                const SERIAL_HALF: $type = 1 << (BITS - 1);
+
+                /// Adds n to a serial number. As defined by RFC 1982, n has to be less than half of the
+                /// number space (e.g. at most 2047 for a u12), otherwise None is returned
+                #[inline]
+                pub const fn serial_add(self, n: Self) -> Option<Self> {
+                    if n.value >= Self::SERIAL_HALF {
+                        None
+                    } else {
+                        Some(self.wrapping_add(n))
+                    }
+                }
+
+                /// Compares two serial numbers, taking wrap-around into account. Returns None if the
+                /// two are exactly half of the number space apart, as the order is undefined in that case
+                #[inline]
+                pub const fn serial_cmp(self, other: Self) -> Option<Ordering> {
+                    let distance = other.wrapping_sub(self).value;
+                    if distance == 0 {
+                        Some(Ordering::Equal)
+                    } else if distance < Self::SERIAL_HALF {
+                        Some(Ordering::Less)
+                    } else if distance > Self::SERIAL_HALF {
+                        Some(Ordering::Greater)
+                    } else {
+                        None
+                    }
+                }
+
+                /// Returns true if self comes before other in serial number arithmetic
+                #[inline]
+                pub const fn serial_lt(self, other: Self) -> bool {
+                    matches!(self.serial_cmp(other), Some(Ordering::Less))
+                }
+
+                /// Returns true if self comes after other in serial number arithmetic
+                #[inline]
+                pub const fn serial_gt(self, other: Self) -> bool {
+                    matches!(self.serial_cmp(other), Some(Ordering::Greater))
+                }
+
+                /// Returns the signed number of steps to get from self to other, taking the shorter
+                /// direction around the wrap. For example, the distance from u12 4095 to 1 is 2 and
+                /// from 1 to 4095 it is -2. If the two are exactly half of the number space apart,
+                /// the result is negative (e.g. -2048 for a u12)
+                #[inline]
+                pub const fn wrapping_distance(self, other: Self) -> $signed {
+                    other.wrapping_sub(self).$sign_extend_fn()
+                }
+            }
+        )+
+    };
+}
+
+serial_impl!(
+    (u8, i8, sign_extend_to_i8),
+    (u16, i16, sign_extend_to_i16),
+    (u32, i32, sign_extend_to_i32),
+    (u64, i64, sign_extend_to_i64),
+    (u128, i128, sign_extend_to_i128),
+    (usize, isize, sign_extend_to_isize)
+);
@@ -0,0 +1,58 @@
+use crate::{CompileTimeAssert, UInt};
+use core::ops::{Index, IndexMut};
+
+// A UInt with BITS bits can't exceed 2^BITS - 1, so an array with at least 2^BITS entries (which is
+// checked at compile time) covers every index. The bounds check is kept nonetheless: Some operations
+// (like overflowing_shl) can produce values with bits above BITS, which must not become UB here.
+macro_rules! array_index_impl {
+    ($($type:ident),+) => {
+        $(
+            impl<V, const N: usize, const BITS: usize> Index<UInt<$type, BITS>> for [V; N] {
+                type Output = V;
+
+                #[inline]
+                fn index(&self, index: UInt<$type, BITS>) -> &V {
+                    let _ = CompileTimeAssert::<BITS, N>::POWER_OF_TWO_SMALLER_OR_EQUAL;
+                    &self[index.value as usize]
+                }
+            }
+
+            impl<V, const N: usize, const BITS: usize> IndexMut<UInt<$type, BITS>> for [V; N] {
+                #[inline]
+                fn index_mut(&mut self, index: UInt<$type, BITS>) -> &mut V {
+                    let _ = CompileTimeAssert::<BITS, N>::POWER_OF_TWO_SMALLER_OR_EQUAL;
+                    &mut self[index.value as usize]
+                }
+            }
+        )+
+    };
+}
+
+array_index_impl!(u8, u16, u32, u64, u128, usize);
+
+/// Builds an array with one entry for every value of a UInt type, so that it can be indexed by
+/// that type without ever going out of bounds. The body is evaluated for every index (in
+/// ascending order) and may be used in const contexts, e.g. for opcode dispatch:
+///
+/// `const DECODE: [Opcode; 16] = lookup_table!(u4, |opcode| decode(opcode));`
+///
+/// The element type has to be `Copy`.
+#[macro_export]
+macro_rules! lookup_table {
+    ($index_type:ty, |$index:ident| $body:expr) => {{
+        const LEN: usize = 1 << <$index_type>::BITS;
+        let mut table = [{
+            let $index = <$index_type as $crate::Number>::MIN;
+            $body
+        }; LEN];
+        let mut i = 1;
+        while i < LEN {
+            table[i] = {
+                let $index = <$index_type>::new(i as _);
+                $body
+            };
+            i += 1;
+        }
+        table
+    }};
+}
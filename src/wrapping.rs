@@ -0,0 +1,128 @@
+use crate::UnsignedNumber;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+/// Provides intentionally-wrapped arithmetic on `T`, like `core::num::Wrapping`. Unlike the one
+/// in core, this works for every `UInt` (and native type), so a `Wrapping<u12>` wraps at 4096.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+macro_rules! wrapping_binop_impl {
+    ($($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $wrapping_fn:ident);+) => {
+        $(
+            impl<T: UnsignedNumber> $trait for Wrapping<T> {
+                type Output = Wrapping<T>;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    Wrapping(self.0.$wrapping_fn(rhs.0))
+                }
+            }
+
+            impl<T: UnsignedNumber> $assign_trait for Wrapping<T> {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = Wrapping(self.0.$wrapping_fn(rhs.0));
+                }
+            }
+        )+
+    };
+}
+
+wrapping_binop_impl!(
+    Add, add, AddAssign, add_assign, wrapping_add;
+    Sub, sub, SubAssign, sub_assign, wrapping_sub;
+    Mul, mul, MulAssign, mul_assign, wrapping_mul;
+    Div, div, DivAssign, div_assign, wrapping_div
+);
+
+// Like core::num::Wrapping, the shift amount is taken modulo the number of bits. This happens in
+// usize, as truncating to u32 first would change the result for widths that aren't a power of two
+#[inline]
+fn shift_amount<T: UnsignedNumber>(rhs: usize) -> u32 {
+    (rhs % T::BITS) as u32
+}
+
+macro_rules! wrapping_shift_impl {
+    ($($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $wrapping_fn:ident);+) => {
+        $(
+            impl<T: UnsignedNumber> $trait<usize> for Wrapping<T> {
+                type Output = Wrapping<T>;
+
+                #[inline]
+                fn $fn(self, rhs: usize) -> Self::Output {
+                    Wrapping(self.0.$wrapping_fn(shift_amount::<T>(rhs)))
+                }
+            }
+
+            impl<T: UnsignedNumber> $assign_trait<usize> for Wrapping<T> {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: usize) {
+                    *self = Wrapping(self.0.$wrapping_fn(shift_amount::<T>(rhs)));
+                }
+            }
+        )+
+    };
+}
+
+wrapping_shift_impl!(
+    Shl, shl, ShlAssign, shl_assign, wrapping_shl;
+    Shr, shr, ShrAssign, shr_assign, wrapping_shr
+);
+
+// Bit operations can't overflow, so they simply forward
+macro_rules! wrapping_bitop_impl {
+    ($($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident);+) => {
+        $(
+            impl<T: UnsignedNumber> $trait for Wrapping<T> {
+                type Output = Wrapping<T>;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    Wrapping(self.0.$fn(rhs.0))
+                }
+            }
+
+            impl<T: UnsignedNumber> $assign_trait for Wrapping<T> {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: Self) {
+                    self.0.$assign_fn(rhs.0);
+                }
+            }
+        )+
+    };
+}
+
+wrapping_bitop_impl!(
+    BitAnd, bitand, BitAndAssign, bitand_assign;
+    BitOr, bitor, BitOrAssign, bitor_assign;
+    BitXor, bitxor, BitXorAssign, bitxor_assign
+);
+
+impl<T: UnsignedNumber> Not for Wrapping<T> {
+    type Output = Wrapping<T>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        Wrapping(!self.0)
+    }
+}
+
+macro_rules! wrapping_fmt_impl {
+    ($($trait:ident),+) => {
+        $(
+            impl<T: $trait> $trait for Wrapping<T> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+        )+
+    };
+}
+
+wrapping_fmt_impl!(Display, Debug, LowerHex, UpperHex, Octal, Binary);
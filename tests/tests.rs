@@ -1,4 +1,5 @@
 #![cfg_attr(feature = "step_trait", feature(step_trait))]
+#![cfg_attr(feature = "const_param_ty", feature(adt_const_params))]
 
 extern crate core;
 
@@ -2047,3 +2048,2151 @@ fn schemars() {
     u8.schema.number = u9.schema.number.clone();
     assert_eq!(u8, u9);
 }
+
+#[test]
+fn width_mapping() {
+    use core::any::TypeId;
+
+    assert_eq!(TypeId::of::<UIntFor<1>>(), TypeId::of::<u1>());
+    assert_eq!(TypeId::of::<UIntFor<13>>(), TypeId::of::<u13>());
+    assert_eq!(TypeId::of::<UIntFor<16>>(), TypeId::of::<UInt<u16, 16>>());
+    assert_eq!(TypeId::of::<UIntFor<127>>(), TypeId::of::<u127>());
+    assert_eq!(
+        TypeId::of::<<Bits<13> as Width>::UInt>(),
+        TypeId::of::<u13>()
+    );
+
+    assert_eq!(TypeId::of::<StorageFor<7>>(), TypeId::of::<u8>());
+    assert_eq!(TypeId::of::<StorageFor<8>>(), TypeId::of::<u8>());
+    assert_eq!(TypeId::of::<StorageFor<9>>(), TypeId::of::<u16>());
+    assert_eq!(TypeId::of::<StorageFor<33>>(), TypeId::of::<u64>());
+    assert_eq!(
+        TypeId::of::<<Bits<13> as Width>::Storage>(),
+        TypeId::of::<u16>()
+    );
+}
+
+#[test]
+fn width_in_generic_code() {
+    fn decode<const N: usize>(raw: u32) -> UIntFor<N>
+    where
+        Bits<N>: Width,
+    {
+        let masked = raw & (u32::MAX >> (32 - N));
+        let Ok(value) = StorageFor::<N>::try_from(masked) else {
+            panic!("masked value always fits");
+        };
+        UIntFor::<N>::new(value)
+    }
+
+    assert_eq!(decode::<5>(0b1111_0110), u5::new(0b1_0110));
+    assert_eq!(decode::<13>(0xFFFF_1234), u13::new(0x1234));
+    assert_eq!(decode::<32>(0x1234_5678), UInt::<u32, 32>::new(0x1234_5678));
+    assert_eq!(<UIntFor<20> as Number>::MAX, u20::MAX);
+}
+
+#[test]
+fn const_conversions_from_native() {
+    const A: u12 = u12::from_u8(200);
+    const B: UInt<u8, 8> = UInt::<u8, 8>::from_u8(255);
+    const C: u40 = u40::from_u32(0xFFFF_FFFF);
+    const D: u100 = u100::from_u64(u64::MAX);
+    const E: UInt<u128, 128> = UInt::<u128, 128>::from_u128(u128::MAX);
+
+    assert_eq!(A, u12::new(200));
+    assert_eq!(B.value(), 255);
+    assert_eq!(C, u40::new(0xFFFF_FFFF));
+    assert_eq!(D, u100::new(u64::MAX as u128));
+    assert_eq!(E.value(), u128::MAX);
+
+    // Matches From
+    assert_eq!(u20::from_u16(1234), u20::from(1234u16));
+    assert_eq!(UInt::<u64, 16>::from_u8(17), UInt::<u64, 16>::from(17u8));
+}
+
+#[test]
+fn const_conversions_into_native() {
+    const A: u8 = u7::new(100).as_u8();
+    const B: u128 = u7::new(100).as_u128();
+    const C: u32 = u20::new(0xF_FFFF).as_u32();
+    const D: u64 = u40::new(0xFF_FFFF_FFFF).as_u64();
+    const E: u16 = u9::new(511).as_u16();
+
+    assert_eq!(A, 100);
+    assert_eq!(B, 100);
+    assert_eq!(C, 0xF_FFFF);
+    assert_eq!(D, 0xFF_FFFF_FFFF);
+    assert_eq!(E, 511);
+
+    // Matches From
+    assert_eq!(u33::new(12345).as_u64(), u64::from(u33::new(12345)));
+    assert_eq!(u6::new(63).as_u16(), u16::from(u6::new(63)));
+}
+
+#[test]
+fn const_conversions_between_uints() {
+    const A: u12 = u5::new(31).widen_to_u16();
+    const B: u100 = u40::new(0xFF_FFFF_FFFF).widen_to_u128();
+    const C: UInt<u8, 7> = UInt::<u32, 7>::new(127).widen_to_u8();
+    const D: u7 = u7::from_uint(u3::new(5));
+    const E: u14 = u14::from_uint(u14::new(0x3FFF));
+
+    assert_eq!(A, u12::new(31));
+    assert_eq!(B, u100::new(0xFF_FFFF_FFFF));
+    assert_eq!(C, u7::new(127));
+    assert_eq!(D, u7::new(5));
+    assert_eq!(E, u14::new(0x3FFF));
+
+    // Matches From
+    assert_eq!(u9::new(300).widen_to_u64::<50>(), u50::from(u9::new(300)));
+    assert_eq!(
+        UInt::<u64, 6>::new(1).widen_to_u8::<8>(),
+        UInt::<u8, 8>::from(UInt::<u64, 6>::new(1))
+    );
+    assert_eq!(u30::from_uint(u20::new(55)), u20::new(55).widen::<30>());
+}
+
+#[test]
+fn unsigned_number_generic_algorithms() {
+    fn gcd<T: UnsignedNumber>(mut a: T, mut b: T) -> T {
+        while b != T::ZERO {
+            let remainder = a - (a / b) * b;
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+
+    fn parity<T: UnsignedNumber>(value: T) -> bool {
+        value.count_ones() & 1 == 1
+    }
+
+    fn lowest_set_bit<T: UnsignedNumber>(value: T) -> T {
+        value & (!value).wrapping_add(T::ONE)
+    }
+
+    fn sum_saturating<T: UnsignedNumber>(values: &[T]) -> T {
+        values
+            .iter()
+            .fold(T::ZERO, |acc, &value| acc.saturating_add(value))
+    }
+
+    assert_eq!(gcd(48u32, 18u32), 6);
+    assert_eq!(gcd(u7::new(48), u7::new(18)), u7::new(6));
+    assert_eq!(
+        gcd(u100::new(1 << 90), u100::new(3 << 40)),
+        u100::new(1 << 40)
+    );
+
+    assert!(parity(0b1011u8));
+    assert!(!parity(u5::new(0b11011)));
+    assert!(parity(u65::new(1 << 64)));
+
+    assert_eq!(lowest_set_bit(0b1011_0000u8), 0b1_0000);
+    assert_eq!(
+        lowest_set_bit(u12::new(0b1000_0000_0000)),
+        u12::new(0b1000_0000_0000)
+    );
+    assert_eq!(lowest_set_bit(u12::new(0)), u12::new(0));
+
+    assert_eq!(sum_saturating(&[200u8, 50, 10]), 255);
+    assert_eq!(
+        sum_saturating(&[u6::new(40), u6::new(20), u6::new(10)]),
+        u6::MAX
+    );
+    assert_eq!(sum_saturating(&[u6::new(40), u6::new(20)]), u6::new(60));
+}
+
+#[test]
+fn unsigned_number_matches_inherent() {
+    fn check<T: UnsignedNumber>(a: T, b: T) -> (T, Option<T>, (T, bool), T, u32, u32, T) {
+        (
+            UnsignedNumber::wrapping_mul(a, b),
+            UnsignedNumber::checked_sub(a, b),
+            UnsignedNumber::overflowing_add(a, b),
+            UnsignedNumber::rotate_left(a, 3),
+            UnsignedNumber::leading_zeros(b),
+            UnsignedNumber::trailing_zeros(b),
+            a << 2u32,
+        )
+    }
+
+    let a = u9::new(0b1_0110_0101);
+    let b = u9::new(0b0_0010_1000);
+    assert_eq!(
+        check(a, b),
+        (
+            a.wrapping_mul(b),
+            a.checked_sub(b),
+            a.overflowing_add(b),
+            a.rotate_left(3),
+            b.leading_zeros(),
+            b.trailing_zeros(),
+            a << 2
+        )
+    );
+    assert_eq!(
+        check(200u8, 40u8),
+        (
+            200u8.wrapping_mul(40),
+            200u8.checked_sub(40),
+            200u8.overflowing_add(40),
+            200u8.rotate_left(3),
+            40u8.leading_zeros(),
+            40u8.trailing_zeros(),
+            200u8 << 2
+        )
+    );
+    assert_eq!(<u9 as UnsignedNumber>::ZERO, u9::new(0));
+    assert_eq!(<u9 as UnsignedNumber>::ONE, u9::new(1));
+}
+
+#[test]
+fn sign_extend() {
+    const NEGATIVE: i16 = u12::new(0xFFF).sign_extend_to_i16();
+    assert_eq!(NEGATIVE, -1);
+
+    assert_eq!(u12::new(0x800).sign_extend_to_i16(), -2048);
+    assert_eq!(u12::new(0x7FF).sign_extend_to_i16(), 2047);
+    assert_eq!(u12::new(0x800).sign_extend_to_i32(), -2048);
+    assert_eq!(u12::new(0x801).sign_extend_to_i64(), -2047);
+    assert_eq!(u12::new(0x801).sign_extend_to_i128(), -2047);
+    assert_eq!(u12::new(0).sign_extend_to_i16(), 0);
+
+    assert_eq!(u1::new(1).sign_extend_to_i8(), -1);
+    assert_eq!(u1::new(0).sign_extend_to_i8(), 0);
+    assert_eq!(u7::new(0b100_0000).sign_extend_to_i8(), -64);
+    assert_eq!(UInt::<u8, 8>::new(0x80).sign_extend_to_i8(), -128);
+    assert_eq!(UInt::<u32, 5>::new(0b10000).sign_extend_to_i8(), -16);
+    assert_eq!(u24::new(0xFF_FFFE).sign_extend_to_i32(), -2);
+    assert_eq!(u40::new(0x80_0000_0000).sign_extend_to_i64(), -(1 << 39));
+    assert_eq!(u127::MAX.sign_extend_to_i128(), -1);
+    assert_eq!(UInt::<u128, 128>::MAX.sign_extend_to_i128(), -1);
+}
+
+#[test]
+fn from_signed_wrapping() {
+    const MINUS_ONE: u12 = u12::from_signed_wrapping(-1);
+    assert_eq!(MINUS_ONE, u12::MAX);
+
+    assert_eq!(u12::from_signed_wrapping(-2048), u12::new(0x800));
+    assert_eq!(u12::from_signed_wrapping(2047), u12::new(0x7FF));
+    // Out of range values are truncated, like an as-cast
+    assert_eq!(u12::from_signed_wrapping(4096 + 5), u12::new(5));
+    assert_eq!(u4::from_signed_wrapping(-17), u4::new(0xF));
+    assert_eq!(u100::from_signed_wrapping(-1), u100::MAX);
+    assert_eq!(
+        UInt::<u128, 128>::from_signed_wrapping(i128::MIN).value(),
+        1 << 127
+    );
+
+    // Roundtrip
+    for value in -32i128..32 {
+        assert_eq!(
+            u6::from_signed_wrapping(value).sign_extend_to_i8() as i128,
+            (value << 122) >> 122
+        );
+    }
+    for value in -2048i32..2048 {
+        assert_eq!(
+            u12::from_signed_wrapping(value as i128).sign_extend_to_i32(),
+            value
+        );
+    }
+}
+
+#[test]
+fn wrapping_operators() {
+    let mut sequence = Wrapping(u12::new(4094));
+    sequence += Wrapping(u12::new(1));
+    assert_eq!(sequence, Wrapping(u12::MAX));
+    sequence += Wrapping(u12::new(1));
+    assert_eq!(sequence, Wrapping(u12::new(0)));
+    sequence -= Wrapping(u12::new(2));
+    assert_eq!(sequence.0, u12::new(4094));
+
+    assert_eq!(
+        Wrapping(u7::new(100)) + Wrapping(u7::new(30)),
+        Wrapping(u7::new(2))
+    );
+    assert_eq!(
+        Wrapping(u7::new(3)) - Wrapping(u7::new(5)),
+        Wrapping(u7::new(126))
+    );
+    assert_eq!(
+        Wrapping(u7::new(64)) * Wrapping(u7::new(3)),
+        Wrapping(u7::new(64))
+    );
+    assert_eq!(
+        Wrapping(u7::new(100)) / Wrapping(u7::new(3)),
+        Wrapping(u7::new(33))
+    );
+    assert_eq!(Wrapping(u7::new(0b100_0001)) << 1, Wrapping(u7::new(0b10)));
+    assert_eq!(Wrapping(u7::new(0b100_0001)) << 8, Wrapping(u7::new(0b10)));
+    assert_eq!(Wrapping(u7::new(0b100_0001)) >> 6, Wrapping(u7::new(1)));
+    assert_eq!(!Wrapping(u7::new(0)), Wrapping(u7::MAX));
+    assert_eq!(
+        Wrapping(u7::new(0b1100)) & Wrapping(u7::new(0b1010)),
+        Wrapping(u7::new(0b1000))
+    );
+    assert_eq!(
+        Wrapping(u7::new(0b1100)) | Wrapping(u7::new(0b1010)),
+        Wrapping(u7::new(0b1110))
+    );
+    assert_eq!(
+        Wrapping(u7::new(0b1100)) ^ Wrapping(u7::new(0b1010)),
+        Wrapping(u7::new(0b0110))
+    );
+
+    let mut shifted = Wrapping(u100::new(1));
+    shifted <<= 99;
+    assert_eq!(shifted.0, u100::new(1 << 99));
+    shifted <<= 1;
+    assert_eq!(shifted.0, u100::new(0));
+
+    // The shift amount wraps at BITS, even if it doesn't fit into a u32
+    #[cfg(target_pointer_width = "64")]
+    {
+        let shift = ((1u64 << 32) + 1) as usize;
+        assert_eq!(Wrapping(u5::new(1)) << shift, Wrapping(u5::new(1 << 2)));
+        assert_eq!(
+            Wrapping(u5::new(0b10000)) >> shift,
+            Wrapping(u5::new(0b100))
+        );
+        let mut shifted = Wrapping(u5::new(1));
+        shifted <<= shift;
+        assert_eq!(shifted, Wrapping(u5::new(1 << 2)));
+        shifted >>= shift;
+        assert_eq!(shifted, Wrapping(u5::new(1)));
+    }
+
+    // Also works for native types
+    assert_eq!(Wrapping(250u8) + Wrapping(10u8), Wrapping(4u8));
+
+    assert_eq!(format!("{}", Wrapping(u12::new(300))), "300");
+    assert_eq!(format!("{:?}", Wrapping(u12::new(300))), "300");
+    assert_eq!(format!("{:x}", Wrapping(u12::new(300))), "12c");
+}
+
+#[test]
+fn saturating_operators() {
+    let mut level = Saturating(u4::new(14));
+    level += Saturating(u4::new(1));
+    assert_eq!(level, Saturating(u4::MAX));
+    level += Saturating(u4::new(1));
+    assert_eq!(level, Saturating(u4::MAX));
+    level -= Saturating(u4::new(10));
+    assert_eq!(level.0, u4::new(5));
+    level -= Saturating(u4::new(10));
+    assert_eq!(level.0, u4::new(0));
+
+    assert_eq!(
+        Saturating(u7::new(100)) + Saturating(u7::new(30)),
+        Saturating(u7::MAX)
+    );
+    assert_eq!(
+        Saturating(u7::new(3)) - Saturating(u7::new(5)),
+        Saturating(u7::new(0))
+    );
+    assert_eq!(
+        Saturating(u7::new(64)) * Saturating(u7::new(3)),
+        Saturating(u7::MAX)
+    );
+    assert_eq!(
+        Saturating(u7::new(20)) * Saturating(u7::new(3)),
+        Saturating(u7::new(60))
+    );
+    assert_eq!(
+        Saturating(u7::new(100)) / Saturating(u7::new(3)),
+        Saturating(u7::new(33))
+    );
+    assert_eq!(!Saturating(u7::new(1)), Saturating(u7::new(126)));
+
+    let mut bits = Saturating(u7::new(0b1100));
+    bits &= Saturating(u7::new(0b0110));
+    assert_eq!(bits, Saturating(u7::new(0b0100)));
+    bits |= Saturating(u7::new(0b0001));
+    assert_eq!(bits, Saturating(u7::new(0b0101)));
+    bits ^= Saturating(u7::new(0b0111));
+    assert_eq!(bits, Saturating(u7::new(0b0010)));
+
+    assert_eq!(Saturating(250u8) + Saturating(10u8), Saturating(255u8));
+    assert_eq!(format!("{}", Saturating(u12::new(300))), "300");
+}
+
+#[test]
+fn serial_cmp() {
+    use core::cmp::Ordering;
+
+    assert_eq!(u12::new(5).serial_cmp(u12::new(5)), Some(Ordering::Equal));
+    assert_eq!(u12::new(5).serial_cmp(u12::new(6)), Some(Ordering::Less));
+    assert_eq!(u12::new(6).serial_cmp(u12::new(5)), Some(Ordering::Greater));
+
+    // Across the wrap
+    assert_eq!(u12::new(4095).serial_cmp(u12::new(0)), Some(Ordering::Less));
+    assert_eq!(
+        u12::new(0).serial_cmp(u12::new(4095)),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        u12::new(4000).serial_cmp(u12::new(100)),
+        Some(Ordering::Less)
+    );
+    assert!(u12::new(4095).serial_lt(u12::new(0)));
+    assert!(!u12::new(4095).serial_gt(u12::new(0)));
+    assert!(u12::new(0).serial_gt(u12::new(4095)));
+
+    // Just below, at and above half of the number space
+    assert_eq!(u12::new(0).serial_cmp(u12::new(2047)), Some(Ordering::Less));
+    assert_eq!(u12::new(0).serial_cmp(u12::new(2048)), None);
+    assert_eq!(
+        u12::new(0).serial_cmp(u12::new(2049)),
+        Some(Ordering::Greater)
+    );
+    assert!(!u12::new(0).serial_lt(u12::new(2048)));
+    assert!(!u12::new(0).serial_gt(u12::new(2048)));
+
+    // Full width types
+    assert_eq!(
+        UInt::<u16, 16>::new(65535).serial_cmp(UInt::<u16, 16>::new(3)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(u1::new(0).serial_cmp(u1::new(1)), None);
+    assert_eq!(u127::MAX.serial_cmp(u127::new(0)), Some(Ordering::Less));
+}
+
+#[test]
+fn serial_add() {
+    assert_eq!(u12::new(4095).serial_add(u12::new(1)), Some(u12::new(0)));
+    assert_eq!(
+        u12::new(4000).serial_add(u12::new(2047)),
+        Some(u12::new(1951))
+    );
+    assert_eq!(u12::new(4000).serial_add(u12::new(2048)), None);
+    assert_eq!(u12::new(0).serial_add(u12::new(0)), Some(u12::new(0)));
+    assert_eq!(u1::new(1).serial_add(u1::new(1)), None);
+    assert_eq!(
+        UInt::<u8, 8>::new(200).serial_add(UInt::<u8, 8>::new(127)),
+        Some(UInt::<u8, 8>::new(71))
+    );
+
+    // Adding within the limit always results in a larger serial number
+    let start = u12::new(3000);
+    for n in 1..2048 {
+        assert!(start.serial_lt(start.serial_add(u12::new(n)).unwrap()));
+    }
+}
+
+#[test]
+fn wrapping_distance() {
+    const DISTANCE: i16 = u12::new(4095).wrapping_distance(u12::new(1));
+    assert_eq!(DISTANCE, 2);
+
+    assert_eq!(u12::new(1).wrapping_distance(u12::new(4095)), -2);
+    assert_eq!(u12::new(10).wrapping_distance(u12::new(10)), 0);
+    assert_eq!(u12::new(10).wrapping_distance(u12::new(2057)), 2047);
+    assert_eq!(u12::new(10).wrapping_distance(u12::new(2058)), -2048);
+    assert_eq!(u7::new(120).wrapping_distance(u7::new(5)), 13);
+    assert_eq!(
+        UInt::<u8, 8>::new(250).wrapping_distance(UInt::<u8, 8>::new(4)),
+        10
+    );
+    assert_eq!(u20::new(0).wrapping_distance(u20::MAX), -1);
+    assert_eq!(u40::new(5).wrapping_distance(u40::new(3)), -2);
+    assert_eq!(u100::new(0).wrapping_distance(u100::MAX), -1);
+    assert_eq!(
+        UInt::<u128, 128>::new(0).wrapping_distance(UInt::<u128, 128>::new(1 << 127)),
+        i128::MIN
+    );
+}
+
+#[test]
+fn atomic_load_store_swap() {
+    use core::sync::atomic::Ordering;
+
+    static STATUS: AtomicUInt<u32, 20> = AtomicUInt::<u32, 20>::new(u20::new(0x1_2345));
+    assert_eq!(STATUS.load(Ordering::Relaxed), u20::new(0x1_2345));
+    STATUS.store(u20::MAX, Ordering::Relaxed);
+    assert_eq!(STATUS.load(Ordering::Relaxed), u20::MAX);
+    assert_eq!(STATUS.swap(u20::new(7), Ordering::Relaxed), u20::MAX);
+    assert_eq!(STATUS.load(Ordering::Relaxed), u20::new(7));
+
+    let atomic = AtomicUInt::<u8, 7>::default();
+    assert_eq!(atomic.load(Ordering::Relaxed), u7::new(0));
+    let atomic = AtomicUInt::from(u7::new(99));
+    assert_eq!(format!("{:?}", atomic), "99");
+    assert_eq!(atomic.into_inner(), u7::new(99));
+}
+
+#[test]
+fn atomic_compare_exchange() {
+    use core::sync::atomic::Ordering;
+
+    let atomic = AtomicUInt::<u16, 12>::new(u12::new(100));
+    assert_eq!(
+        atomic.compare_exchange(u12::new(5), u12::new(6), Ordering::SeqCst, Ordering::SeqCst),
+        Err(u12::new(100))
+    );
+    assert_eq!(
+        atomic.compare_exchange(
+            u12::new(100),
+            u12::new(6),
+            Ordering::SeqCst,
+            Ordering::SeqCst
+        ),
+        Ok(u12::new(100))
+    );
+    assert_eq!(atomic.load(Ordering::SeqCst), u12::new(6));
+
+    let mut current = atomic.load(Ordering::Relaxed);
+    loop {
+        match atomic.compare_exchange_weak(
+            current,
+            current * u12::new(2),
+            Ordering::SeqCst,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => break,
+            Err(actual) => current = actual,
+        }
+    }
+    assert_eq!(atomic.load(Ordering::SeqCst), u12::new(12));
+
+    assert_eq!(
+        atomic.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |value| value
+            .checked_mul(u12::new(1000))),
+        Err(u12::new(12))
+    );
+    assert_eq!(
+        atomic.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |value| value
+            .checked_mul(u12::new(100))),
+        Ok(u12::new(12))
+    );
+    assert_eq!(atomic.load(Ordering::SeqCst), u12::new(1200));
+}
+
+#[test]
+fn atomic_fetch_arithmetic() {
+    use core::sync::atomic::Ordering;
+
+    let atomic = AtomicUInt::<u8, 7>::new(u7::new(126));
+    assert_eq!(
+        atomic.fetch_add(u7::new(3), Ordering::Relaxed),
+        u7::new(126)
+    );
+    assert_eq!(atomic.load(Ordering::Relaxed), u7::new(1));
+    assert_eq!(atomic.fetch_sub(u7::new(2), Ordering::Relaxed), u7::new(1));
+    assert_eq!(atomic.load(Ordering::Relaxed), u7::new(127));
+
+    assert_eq!(
+        atomic.fetch_saturating_add(u7::new(5), Ordering::Relaxed),
+        u7::new(127)
+    );
+    assert_eq!(atomic.load(Ordering::Relaxed), u7::new(127));
+    atomic.store(u7::new(3), Ordering::Relaxed);
+    assert_eq!(
+        atomic.fetch_saturating_sub(u7::new(5), Ordering::Relaxed),
+        u7::new(3)
+    );
+    assert_eq!(atomic.load(Ordering::Relaxed), u7::new(0));
+
+    // Full width falls back to the native operations
+    let atomic = AtomicUInt::<u16, 16>::new(UInt::<u16, 16>::new(65535));
+    atomic.fetch_add(UInt::<u16, 16>::new(2), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed).value(), 1);
+    atomic.fetch_sub(UInt::<u16, 16>::new(3), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed).value(), 65534);
+
+    let atomic = AtomicUInt::<u64, 40>::new(u40::new(0b1100));
+    assert_eq!(
+        atomic.fetch_and(u40::new(0b1010), Ordering::Relaxed),
+        u40::new(0b1100)
+    );
+    assert_eq!(atomic.load(Ordering::Relaxed), u40::new(0b1000));
+    atomic.fetch_or(u40::new(0b0001), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), u40::new(0b1001));
+    atomic.fetch_xor(u40::new(0b1111), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), u40::new(0b0110));
+    atomic.fetch_max(u40::new(100), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), u40::new(100));
+    atomic.fetch_min(u40::new(50), Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), u40::new(50));
+}
+
+#[test]
+fn atomic_shared_between_threads() {
+    use core::sync::atomic::Ordering;
+
+    static COUNTER: AtomicUInt<u8, 7> = AtomicUInt::<u8, 7>::new(u7::new(0));
+
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            std::thread::spawn(|| {
+                for _ in 0..1000 {
+                    COUNTER.fetch_add(u7::new(1), Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    // 4000 increments, wrapping at 128
+    assert_eq!(COUNTER.load(Ordering::Relaxed), u7::new((4000 % 128) as u8));
+}
+
+#[test]
+fn register_read_fields() {
+    use arbitrary_int::register::{ReadOnly, ReadWrite, Register};
+
+    let mut memory = 0xDEAD_BEEFu32;
+    let register = unsafe { Register::<u32, ReadOnly>::new(&mut memory) };
+    assert_eq!(register.read(), 0xDEAD_BEEF);
+    assert_eq!(register.read_field::<u4>(0), u4::new(0xF));
+    assert_eq!(register.read_field::<u12>(4), u12::new(0xBEE));
+    assert_eq!(register.read_field::<u20>(12), u20::new(0xDEADB));
+    assert_eq!(register.read_field::<u8>(24), 0xDE);
+    assert_eq!(register.read_field::<u1>(31), u1::new(1));
+    assert_eq!(register.read_field::<UInt<u32, 32>>(0).value(), 0xDEAD_BEEF);
+    assert_eq!(
+        register.read_field::<u20>(12),
+        u20::extract_u32(0xDEAD_BEEF, 12)
+    );
+
+    let mut memory = [0x1234u16, 0xABCD];
+    let register = unsafe { Register::<u16, ReadWrite>::new(&mut memory[1]) };
+    assert_eq!(
+        register.read_field::<u7>(9),
+        u7::new((0xABCDu16 >> 9) as u8)
+    );
+    assert_eq!(register.read_field::<u16>(0), 0xABCD);
+}
+
+#[test]
+#[should_panic]
+fn register_read_field_too_wide() {
+    use arbitrary_int::register::{ReadOnly, Register};
+
+    let mut memory = 0u32;
+    let register = unsafe { Register::<u32, ReadOnly>::new(&mut memory) };
+    register.read_field::<u12>(21);
+}
+
+#[test]
+fn register_write_fields() {
+    use arbitrary_int::register::{ReadWrite, Register, WriteOnly};
+
+    let mut memory = 0u32;
+    let register = unsafe { Register::<u32, WriteOnly>::new(&mut memory) };
+    register.write(0x1234_5678);
+    assert_eq!(memory, 0x1234_5678);
+
+    let register = unsafe { Register::<u32, ReadWrite>::new(&mut memory) };
+    register.modify_field(4, u12::new(0xABC));
+    assert_eq!(register.read(), 0x1234_ABC8);
+    register.modify_field(28, u4::new(0));
+    assert_eq!(register.read(), 0x0234_ABC8);
+    register.modify_field(0, 0xFFu8);
+    assert_eq!(register.read(), 0x0234_ABFF);
+    register.modify_field(31, u1::new(1));
+    assert_eq!(register.read(), 0x8234_ABFF);
+    register.modify(|value| value >> 4);
+    assert_eq!(memory, 0x0823_4ABF);
+
+    let mut memory = u64::MAX;
+    let register = unsafe { Register::<u64, ReadWrite>::new(&mut memory) };
+    register.modify_field(20, u40::new(0));
+    assert_eq!(memory, 0xF000_0000_000F_FFFF);
+}
+
+#[test]
+#[should_panic]
+fn register_modify_field_too_wide() {
+    use arbitrary_int::register::{ReadWrite, Register};
+
+    let mut memory = 0u16;
+    let register = unsafe { Register::<u16, ReadWrite>::new(&mut memory) };
+    register.modify_field(10, u7::new(1));
+}
+
+#[test]
+fn niche_uint_layout() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<NicheUInt<1>>(), 1);
+    assert_eq!(size_of::<Option<NicheUInt<1>>>(), 1);
+    assert_eq!(size_of::<Option<NicheUInt<7>>>(), 1);
+    assert_eq!(size_of::<Result<NicheUInt<7>, ()>>(), 1);
+    assert_eq!(size_of::<Option<NicheUInt<12>>>(), 2);
+    assert_eq!(size_of::<Option<NicheUInt<31>>>(), 4);
+    assert_eq!(size_of::<Option<NicheUInt<33>>>(), 5);
+    assert_eq!(size_of::<Option<NicheUInt<63>>>(), 8);
+    assert_eq!(size_of::<Option<NicheUInt<127>>>(), 16);
+    assert_eq!(size_of::<[Option<NicheUInt<7>>; 16]>(), 16);
+}
+
+#[test]
+fn niche_uint_roundtrip() {
+    for i in 0..=u7::MAX.value() {
+        assert_eq!(NicheUInt::<7>::new(u7::new(i)).get(), u7::new(i));
+    }
+    for value in [0, 1, 0x1234, 0x1FFF_FFFF, 0x7FFF_FFFF] {
+        assert_eq!(NicheUInt::<31>::new(u31::new(value)).get(), u31::new(value));
+    }
+    assert_eq!(NicheUInt::<127>::MAX.get(), u127::MAX);
+    assert_eq!(NicheUInt::<127>::MIN.get(), u127::MIN);
+    assert_eq!(NicheUInt::<65>::MAX.get(), u65::MAX);
+
+    const NICHE: NicheUInt<12> = NicheUInt::<12>::new(u12::new(0xABC));
+    const VALUE: u12 = NICHE.get();
+    assert_eq!(VALUE, u12::new(0xABC));
+
+    let converted: NicheUInt<20> = u20::new(0xF_1234).into();
+    assert_eq!(u20::from(converted), u20::new(0xF_1234));
+    let table = [None, Some(converted)];
+    assert_eq!(table[1].map(NicheUInt::<20>::get), Some(u20::new(0xF_1234)));
+}
+
+#[test]
+fn niche_uint_traits() {
+    let a = NicheUInt::<12>::new(u12::new(0x0FF));
+    let b = NicheUInt::<12>::new(u12::new(0x100));
+    assert!(a < b);
+    assert_eq!(a.max(b), b);
+    assert_eq!(a, NicheUInt::<12>::new(u12::new(0x0FF)));
+    assert_ne!(a, b);
+    assert_eq!(NicheUInt::<12>::default(), NicheUInt::<12>::MIN);
+    assert_eq!(format!("{}", b), "256");
+    assert_eq!(format!("{:?}", b), "256");
+    assert_eq!(format!("{:x}", b), "100");
+}
+
+#[test]
+fn usize_storage() {
+    let value = UInt::<usize, 10>::new(1000);
+    assert_eq!(value.value(), 1000usize);
+    assert_eq!(UInt::<usize, 10>::MAX.value(), 1023);
+    assert_eq!(UInt::<usize, 10>::try_new(1024), Err(TryNewError));
+    assert_eq!(value.wrapping_add(UInt::<usize, 10>::new(30)).value(), 6);
+    assert_eq!(
+        value.checked_mul(UInt::<usize, 10>::new(2)),
+        None::<UInt<usize, 10>>
+    );
+    assert_eq!(value + UInt::<usize, 10>::new(23), UInt::<usize, 10>::MAX);
+    assert_eq!(value.count_ones(), 6);
+    assert_eq!(value.leading_zeros(), 0);
+    assert_eq!(
+        UInt::<usize, 12>::new(0xFFF).sign_extend_to_isize(),
+        -1isize
+    );
+    assert_eq!(
+        UInt::<usize, 12>::new(1).wrapping_distance(UInt::<usize, 12>::new(4095)),
+        -2isize
+    );
+    assert_eq!(format!("{:?}", value), "1000");
+    assert_eq!(<usize as UnsignedNumber>::ONE, 1);
+}
+
+#[test]
+fn usize_conversions() {
+    let array = [0u8; 1024];
+
+    // Infallible: usize is at least 16 bits
+    let index: usize = u10::new(1000).into();
+    assert_eq!(array[index], 0);
+    assert_eq!(usize::from(u16::new(0xFFFF)), 0xFFFF);
+    assert_eq!(usize::from(UInt::<usize, 10>::new(1000)), 1000);
+
+    // Checked, as they depend on the width of usize
+    assert_eq!(usize::try_from(u20::new(0xF_FFFF)), Ok(0xF_FFFF));
+    assert_eq!(usize::try_from(u127::new(5)), Ok(5));
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(usize::try_from(u127::MAX), Err(TryNewError));
+    assert_eq!(u10::try_from(1023usize), Ok(u10::MAX));
+    assert_eq!(u10::try_from(1024usize), Err(TryNewError));
+    assert_eq!(
+        u100::try_from(usize::MAX).unwrap().value(),
+        usize::MAX as u128
+    );
+    assert_eq!(UInt::<usize, 10>::try_from(1024usize), Err(TryNewError));
+
+    // Between usize based UInts and other types, which are known at compile time
+    assert_eq!(UInt::<usize, 10>::from(u10::new(1000)).value(), 1000);
+    assert_eq!(u10::from(UInt::<usize, 10>::new(1000)), u10::new(1000));
+    assert_eq!(UInt::<usize, 12>::from(0xFFu8).value(), 0xFF);
+    assert_eq!(u16::from(UInt::<usize, 12>::new(0xFFF)), 0xFFF);
+    assert_eq!(UInt::<usize, 12>::new(0xFFF).as_u16(), 0xFFF);
+    assert_eq!(u10::new(1000).as_usize(), 1000);
+    assert_eq!(UInt::<usize, 10>::from_u8(5).value(), 5);
+    assert_eq!(
+        u10::new(1000).widen_to_usize::<16>(),
+        UInt::<usize, 16>::new(1000)
+    );
+}
+
+#[test]
+fn index_array_by_uint() {
+    let mut table = [0u32; 16];
+    for i in 0..16 {
+        table[u4::new(i)] = i as u32 * 10;
+    }
+    assert_eq!(table[u4::new(0)], 0);
+    assert_eq!(table[u4::new(15)], 150);
+    table[u4::new(3)] += 1;
+    assert_eq!(table[u4::new(3)], 31);
+
+    // Larger arrays are fine as well, as are other base data types
+    let table = [1u8; 300];
+    assert_eq!(table[UInt::<u8, 8>::new(255)], 1);
+    assert_eq!(table[UInt::<u64, 4>::new(15)], 1);
+    assert_eq!(table[UInt::<usize, 8>::new(200)], 1);
+    assert_eq!(table[3], 1);
+}
+
+#[test]
+#[should_panic]
+fn index_array_by_uint_out_of_range() {
+    // overflowing_shl doesn't mask the result, so this u4 holds 60
+    let table = [0u32; 16];
+    let _ = table[u4::new(15).overflowing_shl(2).0];
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Opcode {
+    Nop,
+    Load(u3),
+    Store(u3),
+}
+
+const fn decode(opcode: u4) -> Opcode {
+    match opcode.value() {
+        0 => Opcode::Nop,
+        1..=7 => Opcode::Load(u3::extract_u8(opcode.value(), 0)),
+        _ => Opcode::Store(u3::extract_u8(opcode.value(), 0)),
+    }
+}
+
+#[test]
+fn lookup_table() {
+    const DECODE: [Opcode; 16] = lookup_table!(u4, |opcode| decode(opcode));
+    assert_eq!(DECODE[u4::new(0)], Opcode::Nop);
+    assert_eq!(DECODE[u4::new(5)], Opcode::Load(u3::new(5)));
+    assert_eq!(DECODE[u4::new(8)], Opcode::Store(u3::new(0)));
+    assert_eq!(DECODE[u4::new(15)], Opcode::Store(u3::new(7)));
+
+    const SQUARES: [u16; 256] = lookup_table!(UInt<u8, 8>, |x| x.value() as u16 * x.value() as u16);
+    assert_eq!(SQUARES[255], 65025);
+
+    let runtime = lookup_table!(u2, |x| x.value() + 1);
+    assert_eq!(runtime, [1, 2, 3, 4]);
+}
+
+#[test]
+fn match_uint() {
+    fn name(value: u2) -> &'static str {
+        match_uint!(value, {
+            0 => "zero",
+            1 | 2 => "small",
+            3 => "large",
+        })
+    }
+    assert_eq!(name(u2::new(0)), "zero");
+    assert_eq!(name(u2::new(1)), "small");
+    assert_eq!(name(u2::new(2)), "small");
+    assert_eq!(name(u2::new(3)), "large");
+
+    const fn parity(value: UInt<u16, 3>) -> bool {
+        match_uint!(value, { 0 | 3 | 5 | 6 => false, 1 | 2 | 4 | 7 => true })
+    }
+    const _: () = assert!(parity(UInt::<u16, 3>::new(7)));
+    assert!(!parity(UInt::<u16, 3>::new(6)));
+
+    let value = u1::new(1);
+    assert_eq!(match_uint!(value, { 0 => 'a', 1 => 'b' }), 'b');
+    assert!(bool::from(u1::new(1)));
+    assert!(!bool::from(u1::new(0)));
+}
+
+#[test]
+#[should_panic]
+fn match_uint_out_of_range() {
+    // overflowing_shl doesn't mask the result, so this u2 holds 6
+    let value = u2::new(3).overflowing_shl(1).0;
+    let _ = match_uint!(value, { 0 => 'a', 1 => 'b', 2 => 'c', 3 => 'd' });
+}
+
+#[test]
+fn literal_macros() {
+    assert_eq!(u5!(17), u5::new(17));
+    assert_eq!(u5!(0x1F), u5::MAX);
+    assert_eq!(u12!(0b1010_1010), u12::new(0xAA));
+    assert_eq!(u12!(4_095), u12::MAX);
+    assert_eq!(u127!(0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF), u127::MAX);
+
+    const BASE: u8 = 3;
+    assert_eq!(u2!(BASE), u2::new(3));
+    const LITERAL: u7 = u7!(100);
+    assert_eq!(LITERAL.value(), 100);
+}
+
+#[test]
+fn uints_macro() {
+    const DEFAULTS: [u12; 4] = uints![u12; 0x000, 0b1010, 4_095, 7];
+    assert_eq!(
+        DEFAULTS,
+        [u12::new(0), u12::new(10), u12::new(4095), u12::new(7)]
+    );
+
+    let values = uints![UInt<u32, 3>; 1, 2, 3,];
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[2], UInt::<u32, 3>::new(3));
+
+    let empty: [u4; 0] = uints![u4;];
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "const_param_ty")]
+#[test]
+fn uint_as_const_param() {
+    struct Channel<const ID: u5>;
+
+    impl<const ID: u5> Channel<ID> {
+        fn id(&self) -> u5 {
+            ID
+        }
+    }
+
+    assert_eq!(Channel::<{ u5::new(17) }>.id(), u5::new(17));
+    assert_eq!(Channel::<{ u5!(31) }>.id(), u5::MAX);
+
+    struct Wide<const VALUE: u100>;
+    impl<const VALUE: u100> Wide<VALUE> {
+        const DOUBLED: u100 = VALUE.wrapping_add(VALUE);
+    }
+    assert_eq!(Wide::<{ u100::new(21) }>::DOUBLED, u100::new(42));
+}
+
+#[cfg(feature = "const_param_ty")]
+#[test]
+fn uint_const_param_in_where_clause() {
+    struct Channel<const ID: u5>;
+
+    // Only some channels support DMA
+    trait Dma {}
+    impl Dma for Channel<{ u5::new(0) }> {}
+    impl Dma for Channel<{ u5::new(3) }> {}
+
+    fn start_dma<const ID: u5>(_: Channel<ID>) -> u5
+    where
+        Channel<ID>: Dma,
+    {
+        ID
+    }
+
+    assert_eq!(start_dma(Channel::<{ u5::new(3) }>), u5::new(3));
+
+    // Requires two parameters to be the same value
+    trait Same<T> {}
+    impl<T> Same<T> for T {}
+
+    fn connect<const A: u5, const B: u5>(_: Channel<A>, _: Channel<B>) -> bool
+    where
+        Channel<A>: Same<Channel<B>>,
+    {
+        A == B
+    }
+
+    assert!(connect(
+        Channel::<{ u5::new(7) }>,
+        Channel::<{ u5::new(7) }>
+    ));
+}
+
+#[test]
+fn dyn_uint_basics() {
+    let value = DynUInt::new(0xABC, 12);
+    assert_eq!(value.value(), 0xABC);
+    assert_eq!(value.bits(), 12);
+    assert_eq!(DynUInt::try_new(0x1000, 12), Err(TryNewError));
+    assert_eq!(DynUInt::max_value(12).value(), 0xFFF);
+    assert_eq!(DynUInt::max_value(128).value(), u128::MAX);
+    assert_eq!(DynUInt::min_value(7).value(), 0);
+    assert_eq!(DynUInt::extract(0xABCD, 4, 8), DynUInt::new(0xBC, 8));
+    assert_ne!(DynUInt::new(5, 12), DynUInt::new(5, 13));
+    assert_eq!(
+        DynUInt::new(5, 12).partial_cmp(&DynUInt::new(6, 12)),
+        Some(core::cmp::Ordering::Less)
+    );
+    assert_eq!(DynUInt::new(5, 12).partial_cmp(&DynUInt::new(6, 13)), None);
+
+    assert_eq!(format!("{}", value), "2748");
+    assert_eq!(format!("{:?}", value), "2748");
+    assert_eq!(format!("{:x}", value), "abc");
+    assert_eq!(format!("{:#X}", value), "0xABC");
+    assert_eq!(format!("{:o}", DynUInt::new(8, 4)), "10");
+    assert_eq!(format!("{:b}", DynUInt::new(5, 4)), "101");
+}
+
+#[test]
+#[should_panic]
+fn dyn_uint_new_out_of_range() {
+    let _ = DynUInt::new(16, 4);
+}
+
+#[test]
+#[should_panic]
+fn dyn_uint_invalid_bits() {
+    let _ = DynUInt::new(0, 129);
+}
+
+#[test]
+#[should_panic]
+fn dyn_uint_width_mismatch() {
+    let _ = DynUInt::new(1, 4) + DynUInt::new(1, 5);
+}
+
+#[test]
+fn dyn_uint_arithmetic() {
+    let a = DynUInt::new(10, 4);
+    let b = DynUInt::new(9, 4);
+    assert_eq!(a.wrapping_add(b), DynUInt::new(3, 4));
+    assert_eq!(b.wrapping_sub(a), DynUInt::new(15, 4));
+    assert_eq!(a.wrapping_mul(b), DynUInt::new(90 % 16, 4));
+    assert_eq!(a.wrapping_div(b), DynUInt::new(1, 4));
+    assert_eq!(a.wrapping_shl(5), DynUInt::new(4, 4));
+    assert_eq!(a.wrapping_shr(5), DynUInt::new(5, 4));
+    assert_eq!(a.saturating_add(b), DynUInt::new(15, 4));
+    assert_eq!(b.saturating_sub(a), DynUInt::new(0, 4));
+    assert_eq!(a.saturating_mul(b), DynUInt::new(15, 4));
+    assert_eq!(a.saturating_div(b), DynUInt::new(1, 4));
+    assert_eq!(DynUInt::new(3, 4).saturating_pow(2), DynUInt::new(9, 4));
+    assert_eq!(DynUInt::new(3, 4).saturating_pow(3), DynUInt::new(15, 4));
+    assert_eq!(a.checked_add(b), None);
+    assert_eq!(a.checked_add(DynUInt::new(5, 4)), Some(DynUInt::new(15, 4)));
+    assert_eq!(b.checked_sub(a), None);
+    assert_eq!(a.checked_mul(b), None);
+    assert_eq!(a.checked_div(DynUInt::new(0, 4)), None);
+    assert_eq!(a.checked_shl(4), None);
+    assert_eq!(a.checked_shl(1), Some(DynUInt::new(4, 4)));
+    assert_eq!(a.checked_shr(3), Some(DynUInt::new(1, 4)));
+
+    let full = DynUInt::max_value(128);
+    assert_eq!(full.wrapping_add(DynUInt::new(1, 128)).value(), 0);
+    assert_eq!(full.saturating_add(full), full);
+
+    assert_eq!(a + DynUInt::new(5, 4), DynUInt::new(15, 4));
+    assert_eq!(a - b, DynUInt::new(1, 4));
+    assert_eq!(DynUInt::new(3, 4) * DynUInt::new(5, 4), DynUInt::new(15, 4));
+    assert_eq!(a / b, DynUInt::new(1, 4));
+    assert_eq!(a & b, DynUInt::new(8, 4));
+    assert_eq!(a | b, DynUInt::new(11, 4));
+    assert_eq!(a ^ b, DynUInt::new(3, 4));
+    assert_eq!(!a, DynUInt::new(5, 4));
+    assert_eq!(DynUInt::new(3, 4) << 2, DynUInt::new(12, 4));
+    assert_eq!(a >> 1, DynUInt::new(5, 4));
+
+    let mut c = DynUInt::new(1, 20);
+    c += DynUInt::new(2, 20);
+    c *= DynUInt::new(3, 20);
+    c <<= 4;
+    c |= DynUInt::new(1, 20);
+    assert_eq!(c, DynUInt::new(0x91, 20));
+}
+
+#[test]
+fn dyn_uint_bit_operations() {
+    let value = DynUInt::new(0b0011_0100, 10);
+    assert_eq!(value.count_ones(), 3);
+    assert_eq!(value.count_zeros(), 7);
+    assert_eq!(value.leading_zeros(), 4);
+    assert_eq!(value.trailing_zeros(), 2);
+    assert_eq!(DynUInt::new(0, 10).trailing_zeros(), 10);
+    assert_eq!(value.reverse_bits(), DynUInt::new(0b00_1011_0000, 10));
+    assert_eq!(
+        DynUInt::new(0b11_0000_0001, 10).rotate_left(1),
+        DynUInt::new(0b10_0000_0011, 10)
+    );
+    assert_eq!(
+        DynUInt::new(0b11_0000_0001, 10).rotate_right(1),
+        DynUInt::new(0b11_1000_0000, 10)
+    );
+    assert_eq!(value.rotate_left(10), value);
+}
+
+#[test]
+fn dyn_uint_bytes() {
+    let value = DynUInt::from_le_bytes(&[0x56, 0x34, 0x12]);
+    assert_eq!(value, DynUInt::new(0x123456, 24));
+    assert_eq!(DynUInt::from_be_bytes(&[0x12, 0x34, 0x56]), value);
+    assert_eq!(value.swap_bytes(), DynUInt::new(0x563412, 24));
+
+    let mut bytes = [0u8; 3];
+    value.write_le_bytes(&mut bytes);
+    assert_eq!(bytes, [0x56, 0x34, 0x12]);
+    value.write_be_bytes(&mut bytes);
+    assert_eq!(bytes, [0x12, 0x34, 0x56]);
+
+    let full = DynUInt::from_be_bytes(&[0xFF; 16]);
+    assert_eq!(full, DynUInt::max_value(128));
+}
+
+#[test]
+fn dyn_uint_conversions() {
+    let value = DynUInt::from(u12::new(0xABC));
+    assert_eq!(value, DynUInt::new(0xABC, 12));
+    assert_eq!(DynUInt::from(u127::MAX), DynUInt::max_value(127));
+    assert_eq!(DynUInt::from(UInt::<usize, 3>::new(7)), DynUInt::new(7, 3));
+
+    assert_eq!(u12::try_from(value), Ok(u12::new(0xABC)));
+    assert_eq!(u20::try_from(value), Ok(u20::new(0xABC)));
+    assert_eq!(u11::try_from(value), Err(TryNewError));
+    assert_eq!(
+        UInt::<u128, 100>::try_from(DynUInt::new(5, 128)),
+        Ok(UInt::<u128, 100>::new(5))
+    );
+}
+
+type Month = Ranged<u4, 1, 12>;
+
+#[test]
+fn ranged_new() {
+    assert_eq!(Month::new(u4::new(1)).get(), u4::new(1));
+    assert_eq!(Month::new(u4::new(12)).value(), 12);
+    assert_eq!(Month::try_new(u4::new(0)), Err(TryNewError));
+    assert_eq!(Month::try_new(u4::new(13)), Err(TryNewError));
+    assert_eq!(Month::MIN.get(), u4::new(1));
+    assert_eq!(Month::MAX.get(), u4::new(12));
+    assert_eq!(Month::try_from(u4::new(7)), Ok(Month::new(u4::new(7))));
+    assert_eq!(u4::from(Month::new(u4::new(7))), u4::new(7));
+
+    const DECEMBER: Month = Month::new(u4::new(12));
+    assert_eq!(format!("{}", DECEMBER), "12");
+    assert_eq!(format!("{:?}", DECEMBER), "12");
+
+    type Digit = Ranged<u4, 0, 9>;
+    assert_eq!(Digit::new(u4::new(0)).value(), 0);
+    assert_eq!(Digit::try_new(u4::new(10)), Err(TryNewError));
+
+    type Wide = Ranged<u41, 1_000_000_000_000, 2_000_000_000_000>;
+    assert_eq!(
+        Wide::new(u41::new(1_500_000_000_000)).value(),
+        1_500_000_000_000
+    );
+}
+
+#[test]
+#[should_panic]
+fn ranged_new_out_of_range() {
+    let _ = Month::new(u4::new(13));
+}
+
+#[test]
+fn ranged_arithmetic() {
+    let march = Month::new(u4::new(3));
+    assert_eq!(march.checked_add(u4::new(9)), Some(Month::MAX));
+    assert_eq!(march.checked_add(u4::new(10)), None);
+    assert_eq!(march.checked_add(u4::new(15)), None);
+    assert_eq!(march.checked_sub(u4::new(2)), Some(Month::MIN));
+    assert_eq!(march.checked_sub(u4::new(3)), None);
+    assert_eq!(march.checked_mul(u4::new(4)), Some(Month::MAX));
+    assert_eq!(march.checked_mul(u4::new(5)), None);
+    assert_eq!(march.saturating_add(u4::new(15)), Month::MAX);
+    assert_eq!(march.saturating_sub(u4::new(5)), Month::MIN);
+    assert_eq!(march.saturating_mul(u4::new(2)).value(), 6);
+    assert_eq!(march.saturating_mul(u4::new(0)), Month::MIN);
+    assert_eq!(march.saturating_mul(u4::new(15)), Month::MAX);
+    assert!(march < Month::new(u4::new(4)));
+}
+
+#[test]
+fn ranged_layout() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<Month>(), 1);
+    assert_eq!(size_of::<Option<Month>>(), 1);
+    assert_eq!(size_of::<Option<Ranged<u12, 2_000, 4_000>>>(), 2);
+    assert_eq!(size_of::<Option<Ranged<u40, 0, { 1 << 39 }>>>(), 8);
+}
+
+#[test]
+fn non_zero_uint() {
+    assert_eq!(NonZeroUInt::<u8, 5>::new(u5::new(0)), None);
+    let value = NonZeroUInt::<u8, 5>::new(u5::new(17)).unwrap();
+    assert_eq!(value.get(), u5::new(17));
+    assert_eq!(NonZeroUInt::<u8, 5>::MIN.get(), u5::new(1));
+    assert_eq!(NonZeroUInt::<u8, 5>::MAX.get(), u5::MAX);
+    assert_eq!(
+        NonZeroUInt::<u64, 40>::try_from(u40::new(5)).map(NonZeroUInt::<u64, 40>::get),
+        Ok(u40::new(5))
+    );
+    assert_eq!(
+        NonZeroUInt::<u64, 40>::try_from(u40::new(0)),
+        Err(TryNewError)
+    );
+    assert_eq!(u5::from(value), u5::new(17));
+    assert_eq!(
+        unsafe { NonZeroUInt::<u16, 12>::new_unchecked(u12::new(3)) }.get(),
+        u12::new(3)
+    );
+    assert!(NonZeroUInt::<u8, 5>::MIN < value);
+
+    assert_eq!(format!("{}", value), "17");
+    assert_eq!(format!("{:?}", value), "17");
+    assert_eq!(format!("{:x}", value), "11");
+    assert_eq!(format!("{:b}", value), "10001");
+}
+
+#[test]
+fn non_zero_uint_arithmetic() {
+    let value = NonZeroUInt::<u8, 5>::new(u5::new(17)).unwrap();
+    let two = NonZeroUInt::<u8, 5>::new(u5::new(2)).unwrap();
+    assert_eq!(
+        value.checked_add(u5::new(14)).map(|v| v.get()),
+        Some(u5::MAX)
+    );
+    assert_eq!(value.checked_add(u5::new(15)), None);
+    assert_eq!(two.checked_mul(two).map(|v| v.get()), Some(u5::new(4)));
+    assert_eq!(value.checked_mul(two), None);
+    assert_eq!(two.checked_pow(4).map(|v| v.get()), Some(u5::new(16)));
+    assert_eq!(two.checked_pow(0).map(|v| v.get()), Some(u5::new(1)));
+    assert_eq!(two.checked_pow(5), None);
+    assert_eq!(
+        NonZeroUInt::<u8, 5>::MIN.checked_pow(u32::MAX),
+        Some(NonZeroUInt::<u8, 5>::MIN)
+    );
+    assert_eq!(two.checked_pow(u32::MAX), None);
+    assert_eq!(value.saturating_add(u5::new(20)), NonZeroUInt::<u8, 5>::MAX);
+    assert_eq!(value.saturating_mul(two), NonZeroUInt::<u8, 5>::MAX);
+    assert_eq!(two.saturating_pow(3).get(), u5::new(8));
+    assert_eq!(two.saturating_pow(10), NonZeroUInt::<u8, 5>::MAX);
+
+    // Division can't panic
+    assert_eq!(u5::new(17) / two, u5::new(8));
+    let mut divided = u5::new(30);
+    divided /= value;
+    assert_eq!(divided, u5::new(1));
+}
+
+#[test]
+fn non_zero_uint_layout() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<NonZeroUInt<u8, 5>>(), 1);
+    assert_eq!(size_of::<Option<NonZeroUInt<u8, 5>>>(), 1);
+    assert_eq!(size_of::<Option<NonZeroUInt<u32, 20>>>(), 4);
+    assert_eq!(size_of::<Option<NonZeroUInt<u128, 100>>>(), 16);
+}
+
+type UQ4_12 = UFixed<UInt<u16, 16>, 4, 12>;
+type UQ2_8 = UFixed<u10, 2, 8>;
+
+#[test]
+fn ufixed_basics() {
+    let value = UQ2_8::from_bits(u10::new(0b01_1000_0000));
+    assert_eq!(value.to_bits(), u10::new(0x180));
+    assert_eq!(value.int_part(), 1);
+    assert_eq!(value.frac_part(), UQ2_8::from_bits(u10::new(0x80)));
+    assert_eq!(UQ2_8::from_int(3).to_bits(), u10::new(0x300));
+    assert_eq!(UQ2_8::try_from_int(4), Err(TryNewError));
+    assert_eq!(UQ2_8::MIN.to_bits(), u10::new(0));
+    assert_eq!(UQ2_8::MAX.to_bits(), u10::MAX);
+    assert_eq!(UQ2_8::DELTA.to_bits(), u10::new(1));
+    assert!(UQ2_8::from_int(1) < value);
+
+    // Purely fractional and purely integer formats
+    assert_eq!(UFixed::<UInt<u8, 8>, 0, 8>::MAX.int_part(), 0);
+    assert_eq!(UFixed::<UInt<u8, 8>, 8, 0>::from_int(200).int_part(), 200);
+    assert_eq!(
+        UFixed::<UInt<u128, 128>, 0, 128>::try_from_int(1),
+        Err(TryNewError)
+    );
+}
+
+#[test]
+fn ufixed_arithmetic() {
+    let one_and_half = UQ4_12::from_f64(1.5);
+    let two = UQ4_12::from_int(2);
+    assert_eq!(one_and_half.checked_add(two), Some(UQ4_12::from_f64(3.5)));
+    assert_eq!(UQ4_12::MAX.checked_add(UQ4_12::DELTA), None);
+    assert_eq!(one_and_half.checked_sub(two), None);
+    assert_eq!(two.checked_sub(one_and_half), Some(UQ4_12::from_f64(0.5)));
+    assert_eq!(UQ4_12::MAX.wrapping_add(UQ4_12::DELTA), UQ4_12::MIN);
+    assert_eq!(UQ4_12::MIN.wrapping_sub(UQ4_12::DELTA), UQ4_12::MAX);
+    assert_eq!(UQ4_12::MAX.saturating_add(two), UQ4_12::MAX);
+    assert_eq!(one_and_half.saturating_sub(two), UQ4_12::MIN);
+
+    assert_eq!(one_and_half.checked_mul(two), Some(UQ4_12::from_int(3)));
+    assert_eq!(UQ4_12::from_int(8).checked_mul(two), None);
+    assert_eq!(UQ4_12::from_int(8).saturating_mul(two), UQ4_12::MAX);
+    assert_eq!(UQ4_12::from_int(8).wrapping_mul(two), UQ4_12::MIN);
+    assert_eq!(one_and_half * one_and_half, UQ4_12::from_f64(2.25));
+    assert_eq!(one_and_half.checked_div(two), Some(UQ4_12::from_f64(0.75)));
+    assert_eq!(one_and_half.checked_div(UQ4_12::MIN), None);
+    assert_eq!(
+        UQ4_12::from_int(8).checked_div(UQ4_12::from_f64(0.25)),
+        None
+    );
+    assert_eq!(
+        UQ4_12::from_int(8).saturating_div(UQ4_12::from_f64(0.25)),
+        UQ4_12::MAX
+    );
+    assert_eq!(two / UQ4_12::from_int(4), UQ4_12::from_f64(0.5));
+
+    // Rounding to nearest: DELTA * 0.5 is half a DELTA, which rounds up
+    let half = UQ4_12::from_f64(0.5);
+    assert_eq!(UQ4_12::DELTA * half, UQ4_12::DELTA);
+    assert_eq!(UQ4_12::DELTA * UQ4_12::from_f64(0.25), UQ4_12::MIN);
+    // 1/3 = 0.333.. rounds to 1365/4096, while 2/3 rounds up to 2731/4096
+    assert_eq!(
+        (UQ4_12::from_int(1) / UQ4_12::from_int(3)).to_bits(),
+        UInt::<u16, 16>::new(1365)
+    );
+    assert_eq!(
+        (UQ4_12::from_int(2) / UQ4_12::from_int(3)).to_bits(),
+        UInt::<u16, 16>::new(2731)
+    );
+
+    let mut value = UQ4_12::from_int(1);
+    value += one_and_half;
+    value *= two;
+    value -= half;
+    value /= two;
+    assert_eq!(value, UQ4_12::from_f64(2.25));
+
+    // 128 bit values need the wide multiplication and division
+    type Wide = UFixed<UInt<u128, 128>, 64, 64>;
+    let big = Wide::from_int(1 << 40);
+    assert_eq!(big * Wide::from_f64(0.5), Wide::from_int(1 << 39));
+    assert_eq!(big / Wide::from_int(1 << 20), Wide::from_int(1 << 20));
+    assert_eq!(big.checked_mul(big), None);
+    assert_eq!(Wide::from_int(3) / Wide::from_int(4), Wide::from_f64(0.75));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn ufixed_div_overflow() {
+    let _ = UQ4_12::from_int(8) / UQ4_12::from_f64(0.25);
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn ufixed_div_no_overflow() {
+    // 32 doesn't fit into 4 integer bits and wraps around to 0
+    assert_eq!(UQ4_12::from_int(8) / UQ4_12::from_f64(0.25), UQ4_12::MIN);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn ufixed_divassign_overflow() {
+    let mut value = UQ4_12::from_int(8);
+    value /= UQ4_12::from_f64(0.25);
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn ufixed_divassign_no_overflow() {
+    let mut value = UQ4_12::from_int(8);
+    value /= UQ4_12::from_f64(0.25);
+    assert_eq!(value, UQ4_12::MIN);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn ufixed_div_by_zero() {
+    let _ = UQ4_12::from_int(8) / UQ4_12::MIN;
+}
+
+#[test]
+fn ufixed_float_conversions() {
+    assert_eq!(
+        UQ4_12::from_f64(1.5).to_bits(),
+        UInt::<u16, 16>::new(0x1800)
+    );
+    assert_eq!(UQ4_12::from_f64(1.5).to_f64(), 1.5);
+    assert_eq!(UQ4_12::from_f32(0.25).to_f32(), 0.25);
+    assert_eq!(UQ4_12::MAX.to_f64(), 16.0 - 1.0 / 4096.0);
+    // Rounds to the nearest value
+    assert_eq!(
+        UQ4_12::from_f64(1.0 / 3.0).to_bits(),
+        UInt::<u16, 16>::new(1365)
+    );
+    assert_eq!(UQ4_12::try_from_f64(16.0), Err(TryNewError));
+    assert_eq!(UQ4_12::try_from_f64(-0.5), Err(TryNewError));
+    assert_eq!(UQ4_12::try_from_f64(f64::NAN), Err(TryNewError));
+    assert_eq!(UQ4_12::try_from_f32(f32::INFINITY), Err(TryNewError));
+    assert_eq!(UQ4_12::try_from_f64(15.9998), Ok(UQ4_12::MAX));
+    assert_eq!(UQ4_12::try_from_f64(15.9999), Err(TryNewError));
+
+    // 1 + 2^-24 + 2^-54 is above the midpoint between two f32, but rounding it to f64 first
+    // would make it a tie
+    let value = UFixed::<UInt<u64, 64>, 4, 60>::from_bits(UInt::<u64, 64>::new(
+        (1 << 60) + (1 << 36) + (1 << 6),
+    ));
+    assert_eq!(value.to_f32(), 1.0 + f32::EPSILON);
+    assert_eq!(
+        UFixed::<UInt<u128, 128>, 0, 128>::DELTA.to_f32(),
+        f32::from_bits(1 << 21)
+    );
+    assert_eq!(UFixed::<UInt<u128, 128>, 0, 128>::MAX.to_f32(), 1.0);
+    assert_eq!(
+        UFixed::<UInt<u128, 128>, 128, 0>::MAX.to_f32(),
+        f32::INFINITY
+    );
+}
+
+#[test]
+fn ufixed_display() {
+    assert_eq!(format!("{}", UQ4_12::from_f64(1.5)), "1.5");
+    assert_eq!(format!("{}", UQ4_12::from_int(3)), "3");
+    assert_eq!(format!("{}", UQ4_12::DELTA), "0.000244140625");
+    assert_eq!(format!("{}", UQ4_12::MAX), "15.999755859375");
+    assert_eq!(format!("{:.2}", UQ4_12::from_f64(1.5)), "1.50");
+    assert_eq!(format!("{:.3}", UQ4_12::MAX), "16.000");
+    assert_eq!(format!("{:.0}", UQ4_12::from_f64(2.5)), "3");
+    assert_eq!(format!("{:>6}", UQ4_12::from_f64(1.5)), "   1.5");
+    assert_eq!(format!("{:06.2}", UQ4_12::from_f64(1.5)), "001.50");
+    assert_eq!(format!("{:?}", UQ2_8::from_int(2)), "2");
+    assert_eq!(
+        format!("{}", UFixed::<UInt<u8, 8>, 0, 8>::MAX),
+        "0.99609375"
+    );
+}
+
+#[test]
+fn ufixed_format_conversions() {
+    let value = UQ2_8::from_f64(2.75);
+    let widened: UFixed<u12, 3, 9> = value.widen();
+    assert_eq!(widened, UFixed::<u12, 3, 9>::from_f64(2.75));
+    let converted: UFixed<UInt<u32, 20>, 4, 16> = value.into();
+    assert_eq!(converted, UFixed::<UInt<u32, 20>, 4, 16>::from_f64(2.75));
+    let converted: UFixed<UInt<u64, 40>, 20, 20> = converted.into();
+    assert_eq!(converted.to_f64(), 2.75);
+}
+
+type Gf256 = Gf<UInt<u8, 8>, 0x1B>;
+type Gf16 = Gf<u4, 0x3>;
+
+#[test]
+fn gf_add_and_mul() {
+    let a = Gf256::new(UInt::<u8, 8>::new(0x57));
+    let b = Gf256::new(UInt::<u8, 8>::new(0x83));
+    assert_eq!((a + b).value(), 0xD4);
+    assert_eq!((a - b).value(), 0xD4);
+    // The examples from FIPS-197
+    assert_eq!((a * b).value(), 0xC1);
+    assert_eq!((a * Gf256::new(UInt::<u8, 8>::new(0x13))).value(), 0xFE);
+    assert_eq!(a * Gf256::ONE, a);
+    assert_eq!(a * Gf256::ZERO, Gf256::ZERO);
+    assert_eq!(Gf256::GENERATOR.value(), 2);
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= b;
+    assert_eq!(c.value(), 0xC1);
+    c /= b;
+    assert_eq!(c, a);
+
+    // Multiplication is done in const fns
+    const PRODUCT: Gf16 = Gf16::new(u4::new(0b1000)).mul(Gf16::new(u4::new(0b0010)));
+    assert_eq!(PRODUCT.get(), u4::new(0b0011));
+
+    // Full width storage
+    type Gf2_128 = Gf<UInt<u128, 128>, 0x87>;
+    let x = Gf2_128::new(UInt::<u128, 128>::new(1 << 127));
+    assert_eq!((x * Gf2_128::GENERATOR).value(), 0x87);
+    assert_eq!(x * x.inv(), Gf2_128::ONE);
+}
+
+#[test]
+fn gf_inverse_and_pow() {
+    let a = Gf256::new(UInt::<u8, 8>::new(0x53));
+    // The inverse of 0x53 is 0xCA, as in the AES S-box construction
+    assert_eq!(a.inv().value(), 0xCA);
+    assert_eq!(a.checked_inv().map(Gf256::value), Some(0xCA));
+    assert_eq!(Gf256::ZERO.checked_inv(), None);
+    assert_eq!(a.checked_div(Gf256::ZERO), None);
+    assert_eq!(a.checked_div(a), Some(Gf256::ONE));
+    for value in 1..=255u8 {
+        let element = Gf256::new(UInt::<u8, 8>::new(value));
+        assert_eq!(element * element.inv(), Gf256::ONE);
+        assert_eq!(element.pow(255), Gf256::ONE);
+    }
+    assert_eq!(a.pow(0), Gf256::ONE);
+    assert_eq!(a.pow(1), a);
+    assert_eq!(a.pow(3), a * a * a);
+    assert_eq!(Gf256::ZERO.pow(0), Gf256::ONE);
+    assert_eq!(Gf256::ZERO.pow(5), Gf256::ZERO);
+    assert_eq!(format!("{:?} {:x}", a, a), "83 53");
+}
+
+#[test]
+#[should_panic]
+fn gf_inverse_of_zero() {
+    let _ = Gf16::ZERO.inv();
+}
+
+#[test]
+fn gf_tables() {
+    const TABLES: GfTables<u4, 0x3, 16> = GfTables::<u4, 0x3, 16>::new();
+    assert_eq!(TABLES.exp(0), Gf16::ONE);
+    assert_eq!(TABLES.exp(1), Gf16::GENERATOR);
+    assert_eq!(TABLES.exp(4).value(), 0b0011);
+    assert_eq!(TABLES.exp(15), Gf16::ONE);
+    assert_eq!(TABLES.exp_table()[15], Gf16::ONE);
+    assert_eq!(TABLES.log(Gf16::ZERO), None);
+    assert_eq!(TABLES.log(Gf16::new(u4::new(0b0011))), Some(u4::new(4)));
+    assert_eq!(TABLES.log_table()[1], u4::new(0));
+
+    for a in 0..16u8 {
+        let a = Gf16::new(u4::new(a));
+        assert_eq!(TABLES.checked_inv(a), a.checked_inv());
+        assert_eq!(TABLES.pow(a, 7), a.pow(7));
+        assert_eq!(TABLES.pow(a, 0), Gf16::ONE);
+        for b in 0..16u8 {
+            let b = Gf16::new(u4::new(b));
+            assert_eq!(TABLES.mul(a, b), a * b);
+            assert_eq!(TABLES.checked_div(a, b), a.checked_div(b));
+        }
+    }
+
+    // Reed-Solomon over GF(256) usually uses 0x11D, which is primitive unlike the AES polynomial
+    let tables = GfTables::<UInt<u8, 8>, 0x1D, 256>::default();
+    let a = Gf::<UInt<u8, 8>, 0x1D>::new(UInt::<u8, 8>::new(0x53));
+    assert_eq!(tables.mul(a, a), a * a);
+    assert_eq!(tables.exp(8).value(), 0x1D);
+}
+
+#[test]
+#[should_panic(expected = "POLY is not primitive")]
+fn gf_tables_require_primitive_polynomial() {
+    // x^8 + x^4 + x^3 + x + 1 is irreducible, but x only has order 51
+    let _ = GfTables::<UInt<u8, 8>, 0x1B, 256>::new();
+}
+
+#[test]
+fn crc_check_values() {
+    use arbitrary_int::crc::*;
+
+    macro_rules! check {
+        ($($t:ty: $params:expr),+ $(,)?) => {
+            $(
+                let bitwise = Crc::<$t, 0>::new($params);
+                let nibble = Crc::<$t, 16>::new($params);
+                let table = Crc::<$t>::new($params);
+                assert_eq!(bitwise.checksum(b"123456789"), $params.check, "{} (bitwise)", stringify!($params));
+                assert_eq!(nibble.checksum(b"123456789"), $params.check, "{} (nibble)", stringify!($params));
+                assert_eq!(table.checksum(b"123456789"), $params.check, "{} (table)", stringify!($params));
+            )+
+        };
+    }
+
+    check!(
+        u3: CRC_3_GSM,
+        u4: CRC_4_G_704,
+        u5: CRC_5_EPC_C1G2,
+        u5: CRC_5_G_704,
+        u5: CRC_5_USB,
+        u6: CRC_6_G_704,
+        u7: CRC_7_MMC,
+        UInt<u8, 8>: CRC_8_SMBUS,
+        UInt<u8, 8>: CRC_8_AUTOSAR,
+        u10: CRC_10_ATM,
+        u11: CRC_11_FLEXRAY,
+        u12: CRC_12_UMTS,
+        u13: CRC_13_BBC,
+        u14: CRC_14_DARC,
+        u15: CRC_15_CAN,
+        UInt<u16, 16>: CRC_16_ARC,
+        UInt<u16, 16>: CRC_16_IBM_3740,
+        UInt<u16, 16>: CRC_16_KERMIT,
+        UInt<u16, 16>: CRC_16_MODBUS,
+        u17: CRC_17_CAN_FD,
+        u21: CRC_21_CAN_FD,
+        u24: CRC_24_BLE,
+        u24: CRC_24_OPENPGP,
+        u30: CRC_30_CDMA,
+        u31: CRC_31_PHILIPS,
+        UInt<u32, 32>: CRC_32_ISO_HDLC,
+        UInt<u32, 32>: CRC_32_ISCSI,
+        u40: CRC_40_GSM,
+        UInt<u64, 64>: CRC_64_ECMA_182,
+        UInt<u64, 64>: CRC_64_XZ,
+        u82: CRC_82_DARC,
+    );
+}
+
+#[test]
+fn crc_digest() {
+    use arbitrary_int::crc::*;
+
+    const CAN: Crc<u15> = Crc::<u15>::new(CRC_15_CAN);
+    const CHECK: u15 = CAN.checksum(b"123456789");
+    assert_eq!(CHECK, u15::new(0x059e));
+    assert_eq!(CAN.params(), &CRC_15_CAN);
+
+    // Feeding in pieces, as bytes, bits or values gives the same result
+    let usb = Crc::<u5, 16>::new(CRC_5_USB);
+    let mut digest = CAN.digest();
+    digest.update(b"1234");
+    digest.update(b"56789");
+    assert_eq!(digest.finalize(), CHECK);
+    let mut digest = usb.digest();
+    digest.update(b"12");
+    for byte in b"3456789" {
+        digest.update_value(*byte);
+    }
+    assert_eq!(digest.finalize(), u5::new(0x19));
+    let mut digest = CAN.digest();
+    for byte in b"123456789" {
+        digest.update_bits((0..8).rev().map(|i| byte & (1 << i) != 0));
+    }
+    assert_eq!(digest.finalize(), CHECK);
+    let mut digest = usb.digest();
+    for byte in b"123456789" {
+        digest.update_bits((0..8).map(|i| byte & (1 << i) != 0));
+    }
+    assert_eq!(digest.finalize(), u5::new(0x19));
+
+    // A CAN frame that isn't a multiple of 8 bits: Appending the CRC to the bit stream leaves a
+    // remainder of zero
+    let bitwise = Crc::<u15, 0>::new(CRC_15_CAN);
+    let mut digest = CAN.digest();
+    let mut bitwise_digest = bitwise.digest();
+    digest.update_bit(false);
+    bitwise_digest.update_bit(false);
+    digest.update_value(u11::new(0x123));
+    bitwise_digest.update_value(u11::new(0x123));
+    digest.update_value(u7::new(0b000_0010));
+    bitwise_digest.update_value(u7::new(0b000_0010));
+    digest.update(&[0xAB, 0xCD]);
+    bitwise_digest.update(&[0xAB, 0xCD]);
+    let crc = digest.finalize();
+    assert_eq!(crc, bitwise_digest.finalize());
+    assert_ne!(crc, u15::new(0));
+    digest.update_value(crc);
+    assert_eq!(digest.finalize(), u15::new(0));
+}
+
+#[test]
+fn lfsr_prbs_periods() {
+    use arbitrary_int::lfsr::*;
+
+    // All-ones seed: The first zeros take six steps to reach the taps
+    let start: Vec<bool> = Prbs7::default().take(8).collect();
+    assert_eq!(
+        start,
+        [false, false, false, false, false, false, true, false]
+    );
+
+    fn period<I: Iterator<Item = bool> + Clone>(lfsr: I, state: impl Fn(&I) -> u128) -> usize {
+        let mut lfsr = lfsr;
+        let seed = state(&lfsr);
+        let mut steps = 0;
+        loop {
+            lfsr.next();
+            steps += 1;
+            if state(&lfsr) == seed {
+                return steps;
+            }
+        }
+    }
+    assert_eq!(period(Prbs7::default(), |l| l.state().value() as u128), 127);
+    assert_eq!(period(Prbs9::default(), |l| l.state().value() as u128), 511);
+    assert_eq!(
+        period(Prbs11::default(), |l| l.state().value() as u128),
+        2047
+    );
+    assert_eq!(
+        period(Prbs15::default(), |l| l.state().value() as u128),
+        32767
+    );
+    assert_eq!(
+        period(GaloisLfsr::<u7, 0x60>::default(), |l| l.state().value()
+            as u128),
+        127
+    );
+
+    // Jumping by the period returns to the seed
+    let mut prbs = Prbs20::new(u20::new(0x12345));
+    prbs.jump((1 << 20) - 1);
+    assert_eq!(prbs.state(), u20::new(0x12345));
+    let mut prbs = Prbs23::default();
+    prbs.jump((1 << 23) - 1);
+    assert_eq!(prbs, Prbs23::default());
+    let mut prbs = Prbs31::default();
+    prbs.jump((1 << 31) - 1);
+    assert_eq!(prbs, Prbs31::default());
+    prbs.jump((1 << 30) - 1);
+    assert_ne!(prbs, Prbs31::default());
+}
+
+#[test]
+fn lfsr_jump_and_words() {
+    use arbitrary_int::lfsr::*;
+
+    // Galois and Fibonacci form of the same polynomial produce the same sequence, shifted
+    let fibonacci: Vec<bool> = Prbs7::default().take(254).collect();
+    let galois: Vec<bool> = GaloisLfsr::<u7, 0x60>::default().take(127).collect();
+    assert!(fibonacci.windows(127).any(|window| window == galois));
+
+    for steps in [0, 1, 2, 6, 7, 100, 126, 127, 1000] {
+        let mut stepped = Prbs7::new(u7::new(0x35));
+        for _ in 0..steps {
+            stepped.next_bit();
+        }
+        let mut jumped = Prbs7::new(u7::new(0x35));
+        jumped.jump(steps);
+        assert_eq!(jumped, stepped);
+
+        let mut stepped = GaloisLfsr::<u13, 0x100D>::new(u13::new(0x1234));
+        for _ in 0..steps {
+            stepped.next_bit();
+        }
+        let mut jumped = GaloisLfsr::<u13, 0x100D>::new(u13::new(0x1234));
+        jumped.jump(steps);
+        assert_eq!(jumped, stepped);
+    }
+
+    // x^128 + x^7 + x^2 + x + 1
+    type Wide = GaloisLfsr<UInt<u128, 128>, 0x8000_0000_0000_0000_0000_0000_0000_0043>;
+    let mut stepped = Wide::default();
+    for _ in 0..1000 {
+        stepped.next_bit();
+    }
+    let mut jumped = Wide::default();
+    jumped.jump(1000);
+    assert_eq!(jumped, stepped);
+    type WideFibonacci = FibonacciLfsr<UInt<u128, 128>, 0x8000_0000_0000_0000_0000_0000_0000_0043>;
+    let mut stepped = WideFibonacci::default();
+    for _ in 0..1000 {
+        stepped.next_bit();
+    }
+    let mut jumped = WideFibonacci::default();
+    jumped.jump(1000);
+    assert_eq!(jumped, stepped);
+
+    // Words are filled starting with the most significant bit
+    let bits: Vec<bool> = Prbs15::default().take(32).collect();
+    let bytes: Vec<u8> = Prbs15::default().words::<u8>().take(4).collect();
+    for (i, byte) in bytes.iter().enumerate() {
+        for bit in 0..8 {
+            assert_eq!(byte & (0x80 >> bit) != 0, bits[i * 8 + bit]);
+        }
+    }
+    let nibbles: Vec<u4> = Prbs15::default().words::<u4>().take(8).collect();
+    assert_eq!(nibbles[0].value(), bytes[0] >> 4);
+    assert_eq!(nibbles[1].value(), bytes[0] & 0xF);
+    let mut prbs = Prbs15::default();
+    assert_eq!(prbs.next_word::<u8>(), bytes[0]);
+    assert_eq!(prbs.next_word::<u8>(), bytes[1]);
+    assert_eq!(
+        prbs.words::<u16>().next(),
+        Some(u16::from_be_bytes([bytes[2], bytes[3]]))
+    );
+}
+
+#[test]
+#[should_panic(expected = "seed must not be zero")]
+fn lfsr_zero_seed() {
+    let _ = arbitrary_int::lfsr::Prbs7::new(u7::new(0));
+}
+
+#[test]
+fn gray_code() {
+    assert_eq!(u10::new(0).to_gray(), u10::new(0));
+    assert_eq!(u10::new(1).to_gray(), u10::new(1));
+    assert_eq!(u10::new(2).to_gray(), u10::new(0b11));
+    assert_eq!(u10::new(3).to_gray(), u10::new(0b10));
+    assert_eq!(u10::MAX.to_gray(), u10::new(0b10_0000_0000));
+    for value in 0..1024u16 {
+        let value = u10::new(value);
+        let gray = value.to_gray();
+        assert_eq!(gray.from_gray(), value);
+        if value < u10::MAX {
+            assert_eq!((gray ^ (value + u10::new(1)).to_gray()).count_ones(), 1);
+        }
+    }
+    let value = UInt::<u128, 128>::new(0xDEAD_BEEF_0123_4567_89AB_CDEF_FEDC_BA98);
+    assert_eq!(value.to_gray().from_gray(), value);
+    const GRAY: u5 = u5::new(0b10110).from_gray();
+    assert_eq!(GRAY, u5::new(0b11011));
+}
+
+#[test]
+fn bcd() {
+    assert_eq!(
+        UInt::<u8, 8>::new(59).to_bcd(),
+        Ok(UInt::<u8, 8>::new(0x59))
+    );
+    assert_eq!(
+        UInt::<u8, 8>::new(99).to_bcd(),
+        Ok(UInt::<u8, 8>::new(0x99))
+    );
+    assert_eq!(UInt::<u8, 8>::new(100).to_bcd(), Err(TryNewError));
+    assert_eq!(u7::new(79).to_bcd(), Ok(u7::new(0x79)));
+    assert_eq!(u7::new(80).to_bcd(), Err(TryNewError));
+    assert_eq!(u6::new(23).to_bcd(), Ok(u6::new(0x23)));
+    assert_eq!(u12::new(0).to_bcd(), Ok(u12::new(0)));
+    assert_eq!(u12::new(999).to_bcd(), Ok(u12::new(0x999)));
+
+    assert_eq!(
+        UInt::<u8, 8>::new(0x59).from_bcd(),
+        Ok(UInt::<u8, 8>::new(59))
+    );
+    assert_eq!(
+        UInt::<u8, 8>::new(0x5A).from_bcd(),
+        Err(InvalidEncodingError)
+    );
+    assert_eq!(
+        UInt::<u8, 8>::new(0xA5).from_bcd(),
+        Err(InvalidEncodingError)
+    );
+    assert_eq!(u12::new(0x999).from_bcd(), Ok(u12::new(999)));
+    for value in 0..1000u16 {
+        let value = u12::new(value);
+        assert_eq!(value.to_bcd().unwrap().from_bcd(), Ok(value));
+    }
+    let largest = UInt::<u128, 128>::new(0x9999_9999_9999_9999_9999_9999_9999_9999);
+    assert_eq!(
+        largest.from_bcd(),
+        Ok(UInt::<u128, 128>::new(
+            99_999_999_999_999_999_999_999_999_999_999
+        ))
+    );
+    assert_eq!(largest.from_bcd().unwrap().to_bcd(), Ok(largest));
+    assert_eq!(UInt::<u128, 128>::MAX.to_bcd(), Err(TryNewError));
+    const SECONDS: u7 = match u7::new(0x42).from_bcd() {
+        Ok(value) => value,
+        Err(_) => panic!(),
+    };
+    assert_eq!(SECONDS, u7::new(42));
+}
+
+#[test]
+fn one_hot_and_thermometer() {
+    assert_eq!(
+        UInt::<u8, 8>::one_hot(u3::new(5)),
+        UInt::<u8, 8>::new(0b0010_0000)
+    );
+    assert_eq!(UInt::<u8, 8>::one_hot(u3::new(0)), UInt::<u8, 8>::new(1));
+    assert_eq!(u4::one_hot(u2::new(3)), u4::new(0b1000));
+    assert_eq!(
+        UInt::<u128, 128>::one_hot(u7::new(127)),
+        UInt::<u128, 128>::new(1 << 127)
+    );
+    for index in 0..16u8 {
+        let index = u4::new(index);
+        assert_eq!(UInt::<u16, 16>::one_hot(index).decode_one_hot(), Ok(index));
+    }
+    assert_eq!(
+        UInt::<u8, 8>::new(0b0110).decode_one_hot::<3>(),
+        Err(InvalidEncodingError)
+    );
+    assert_eq!(
+        UInt::<u8, 8>::new(0).decode_one_hot::<3>(),
+        Err(InvalidEncodingError)
+    );
+    // A wider type than necessary is allowed, but the index has to fit
+    assert_eq!(u10::one_hot(u3::new(7)), u10::new(0x80));
+    assert_eq!(u10::new(0x80).decode_one_hot(), Ok(u3::new(7)));
+    assert_eq!(
+        u10::new(0x100).decode_one_hot::<3>(),
+        Err(InvalidEncodingError)
+    );
+
+    assert_eq!(
+        UInt::<u8, 8>::thermometer(u3::new(0)),
+        UInt::<u8, 8>::new(0)
+    );
+    assert_eq!(
+        UInt::<u8, 8>::thermometer(u3::new(5)),
+        UInt::<u8, 8>::new(0b1_1111)
+    );
+    assert_eq!(
+        UInt::<u8, 8>::thermometer(u3::new(7)),
+        UInt::<u8, 8>::new(0b111_1111)
+    );
+    for count in 0..128u8 {
+        let count = u7::new(count);
+        assert_eq!(
+            UInt::<u128, 128>::thermometer(count).decode_thermometer(),
+            Ok(count)
+        );
+    }
+    assert_eq!(
+        UInt::<u8, 8>::new(0b1011).decode_thermometer::<3>(),
+        Err(InvalidEncodingError)
+    );
+    assert_eq!(
+        UInt::<u8, 8>::new(0b1110).decode_thermometer::<3>(),
+        Err(InvalidEncodingError)
+    );
+    // All 8 bits set is a count of 8, which doesn't fit into a u3
+    assert_eq!(
+        UInt::<u8, 8>::MAX.decode_thermometer::<3>(),
+        Err(InvalidEncodingError)
+    );
+}
+
+#[test]
+fn concat_and_split() {
+    // The parts have a different base data type than the result
+    let value = u12::concat_u8(u4::new(0xA), UInt::<u8, 8>::new(0xBC));
+    assert_eq!(value, u12::new(0xABC));
+    let (hi, lo): (u4, UInt<u8, 8>) = value.split_u8::<8, _>();
+    assert_eq!(hi, u4::new(0xA));
+    assert_eq!(lo, UInt::<u8, 8>::new(0xBC));
+    let (hi, lo) = value.split_u8::<7, 5>();
+    assert_eq!(hi, u5::new(0b10101));
+    assert_eq!(lo, u7::new(0b0111100));
+
+    // The same base data type
+    let value = UInt::<u16, 12>::concat_u16(UInt::<u16, 4>::new(0xA), UInt::<u16, 8>::new(0xBC));
+    assert_eq!(value, u12::new(0xABC));
+    let (hi, lo): (UInt<u16, 4>, UInt<u16, 8>) = value.split_u16::<8, _>();
+    assert_eq!((hi.value(), lo.value()), (0xA, 0xBC));
+
+    // Inferred from the arguments
+    assert_eq!(
+        u7::concat_u8(u3::new(0b101), u4::new(0b0011)),
+        u7::new(0b1010011)
+    );
+    let value = u24::concat_u16(u12::new(0xDEA), u12::new(0x0DB));
+    assert_eq!(value, u24::new(0xDEA0DB));
+    let (hi, lo): (UInt<u32, 1>, u23) = value.split_u32::<23, _>();
+    assert_eq!((hi.value(), lo.value()), (1, 0x5EA0DB));
+
+    // Full 128 bits and usize
+    let wide = UInt::<u128, 128>::concat_u64(
+        UInt::<u64, 64>::new(0x0123_4567_89AB_CDEF),
+        UInt::<u64, 64>::new(0xFEDC_BA98_7654_3210),
+    );
+    assert_eq!(wide.value(), 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210);
+    let (hi, lo): (u127, UInt<u128, 1>) = wide.split_u128::<1, _>();
+    assert_eq!(hi.value(), 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210 >> 1);
+    assert_eq!(lo.value(), 0);
+    let value = UInt::<usize, 16>::concat_u16(UInt::<u16, 4>::new(0x1), u12::new(0x234));
+    assert_eq!(value.value(), 0x1234);
+    let (hi, lo): (UInt<usize, 8>, UInt<usize, 8>) = value.split_usize::<8, _>();
+    assert_eq!((hi.value(), lo.value()), (0x12, 0x34));
+
+    const CONCATENATED: u20 = u20::concat_u16(u12::new(0x123), UInt::<u16, 8>::new(0x45));
+    assert_eq!(CONCATENATED, u20::new(0x12345));
+    const SPLIT: (UInt<u16, 10>, UInt<u16, 10>) = CONCATENATED.split_u16::<10, _>();
+    assert_eq!(SPLIT.0.value(), 0x48);
+    assert_eq!(SPLIT.1.value(), 0x345);
+}
+
+#[test]
+fn gather_and_scatter() {
+    // Bits 3, 7 and 12..=15 form a u6
+    const MASK: u128 = 0b1111_0000_1000_1000;
+    let register: u32 = 0b1010_0101_1000_0000;
+    assert_eq!(u6::gather_u32::<MASK>(register), u6::new(0b10_1010));
+    assert_eq!(
+        u6::new(0b10_1010).scatter_u32::<MASK>(),
+        0b1010_0000_1000_0000
+    );
+    assert_eq!(u6::new(0b11_1111).scatter_u16::<MASK>(), MASK as u16);
+    const GATHERED: u6 = u6::gather_u16::<MASK>(0xFFFF);
+    assert_eq!(GATHERED, u6::MAX);
+    const SCATTERED: u32 = u6::new(0b10_1010).scatter_u32::<MASK>();
+    assert_eq!(SCATTERED, 0b1010_0000_1000_0000);
+
+    // Compare against a bit-by-bit reference for masks spanning both halves of a u128
+    let mut value: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+    for _ in 0..100 {
+        let expected = reference_gather(value, WIDE_GATHER_MASK);
+        let gathered = u18::gather_u128::<WIDE_GATHER_MASK>(value);
+        assert_eq!(gathered.value() as u128, expected);
+        assert_eq!(
+            gathered.scatter_u128::<WIDE_GATHER_MASK>(),
+            value & WIDE_GATHER_MASK
+        );
+        value = value.rotate_left(7).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+
+    // Full width masks and a result with a smaller base data type than the source
+    assert_eq!(
+        UInt::<u64, 64>::gather_u64::<{ u64::MAX as u128 }>(0x1234),
+        UInt::<u64, 64>::new(0x1234)
+    );
+    assert_eq!(
+        UInt::<u128, 128>::gather_u128::<{ u128::MAX }>(u128::MAX - 1),
+        UInt::<u128, 128>::new(u128::MAX - 1)
+    );
+    let mask = 0xFF00_0000_0000_0000_0000_0000_0000_0000;
+    assert_eq!(
+        UInt::<u8, 8>::gather_u128::<0xFF00_0000_0000_0000_0000_0000_0000_0000>(mask),
+        UInt::<u8, 8>::MAX
+    );
+    assert_eq!(u3::gather_usize::<0b1010_0001>(0b1000_0001), u3::new(0b101));
+    assert_eq!(u3::new(0b011).scatter_u8::<0b1010_0001>(), 0b0010_0001);
+}
+
+const WIDE_GATHER_MASK: u128 = 0xF000_0000_0000_00F0_0FF0_0000_0000_8001;
+
+fn reference_gather(value: u128, mask: u128) -> u128 {
+    let mut result = 0;
+    let mut result_bit = 0;
+    for bit in 0..128 {
+        if mask & (1 << bit) != 0 {
+            result |= ((value >> bit) & 1) << result_bit;
+            result_bit += 1;
+        }
+    }
+    result
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+#[test]
+fn gather_and_scatter_bmi2() {
+    const MASK: u128 = 0b1111_0000_1000_1000;
+    let register: u32 = 0b1010_0101_1000_0000;
+    assert_eq!(u6::pext_u32::<MASK>(register), u6::new(0b10_1010));
+    assert_eq!(u6::new(0b10_1010).pdep_u32::<MASK>(), 0b1010_0000_1000_0000);
+    assert_eq!(u3::pext_usize::<0b1010_0001>(0b1000_0001), u3::new(0b101));
+    assert_eq!(u3::new(0b011).pdep_u8::<0b1010_0001>(), 0b0010_0001);
+
+    let mut value: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+    for _ in 0..100 {
+        let gathered = u18::pext_u128::<WIDE_GATHER_MASK>(value);
+        assert_eq!(
+            gathered.value() as u128,
+            reference_gather(value, WIDE_GATHER_MASK)
+        );
+        assert_eq!(gathered, u18::gather_u128::<WIDE_GATHER_MASK>(value));
+        assert_eq!(
+            gathered.pdep_u128::<WIDE_GATHER_MASK>(),
+            gathered.scatter_u128::<WIDE_GATHER_MASK>()
+        );
+        value = value.rotate_left(7).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+    assert_eq!(
+        UInt::<u128, 128>::pext_u128::<{ u128::MAX }>(u128::MAX - 1),
+        UInt::<u128, 128>::new(u128::MAX - 1)
+    );
+}
+
+#[test]
+fn morton() {
+    fn reference_interleave(coordinates: &[u128], coordinate_bits: usize) -> u128 {
+        let mut result = 0;
+        for bit in 0..coordinate_bits {
+            for (index, coordinate) in coordinates.iter().enumerate() {
+                result |= ((coordinate >> bit) & 1) << (bit * coordinates.len() + index);
+            }
+        }
+        result
+    }
+
+    assert_eq!(
+        UInt::<u8, 8>::interleave_2d(u4::new(0b1111), u4::new(0b0000)),
+        UInt::<u8, 8>::new(0b0101_0101)
+    );
+    assert_eq!(
+        UInt::<u8, 6>::interleave_3d(u2::new(0b01), u2::new(0b10), u2::new(0b11)),
+        UInt::<u8, 6>::new(0b110_101)
+    );
+
+    let mut value: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+    for _ in 0..100 {
+        let x = value as u64;
+        let y = (value >> 64) as u64;
+        let z = value.rotate_left(29) as u64;
+
+        // 2D with the key in a larger base data type
+        let (x16, y16) = (
+            UInt::<u16, 16>::new(x as u16),
+            UInt::<u16, 16>::new(y as u16),
+        );
+        let key = UInt::<u32, 32>::interleave_2d(x16, y16);
+        assert_eq!(
+            key.value() as u128,
+            reference_interleave(&[x as u16 as u128, y as u16 as u128], 16)
+        );
+        assert_eq!(key.deinterleave_2d(), (x16, y16));
+
+        // 3D with 21 bit coordinates in a 63 bit key
+        let (x21, y21, z21) = (
+            u21::new(x as u32 & 0x1F_FFFF),
+            u21::new(y as u32 & 0x1F_FFFF),
+            u21::new(z as u32 & 0x1F_FFFF),
+        );
+        let key = u63::interleave_3d(x21, y21, z21);
+        assert_eq!(
+            key.value() as u128,
+            reference_interleave(
+                &[
+                    x21.value() as u128,
+                    y21.value() as u128,
+                    z21.value() as u128
+                ],
+                21
+            )
+        );
+        assert_eq!(key.deinterleave_3d(), (x21, y21, z21));
+
+        // Keys wider than 64 bits
+        let (x64, y64) = (UInt::<u64, 64>::new(x), UInt::<u64, 64>::new(y));
+        let key = UInt::<u128, 128>::interleave_2d(x64, y64);
+        assert_eq!(
+            key.value(),
+            reference_interleave(&[x as u128, y as u128], 64)
+        );
+        assert_eq!(key.deinterleave_2d(), (x64, y64));
+
+        let mask = (1u64 << 42) - 1;
+        let (x42, y42, z42) = (u42::new(x & mask), u42::new(y & mask), u42::new(z & mask));
+        let key = u126::interleave_3d(x42, y42, z42);
+        assert_eq!(
+            key.value(),
+            reference_interleave(
+                &[
+                    x42.value() as u128,
+                    y42.value() as u128,
+                    z42.value() as u128
+                ],
+                42
+            )
+        );
+        assert_eq!(key.deinterleave_3d(), (x42, y42, z42));
+
+        // Odd widths
+        let (x5, y5) = (u5::new(x as u8 & 0x1F), u5::new(y as u8 & 0x1F));
+        let key = UInt::<u16, 10>::interleave_2d(x5, y5);
+        assert_eq!(
+            key.value() as u128,
+            reference_interleave(&[x5.value() as u128, y5.value() as u128], 5)
+        );
+        assert_eq!(key.deinterleave_2d(), (x5, y5));
+
+        let (x10, y10, z10) = (
+            u10::new(x as u16 & 0x3FF),
+            u10::new(y as u16 & 0x3FF),
+            u10::new(z as u16 & 0x3FF),
+        );
+        let key = u30::interleave_3d(x10, y10, z10);
+        assert_eq!(
+            key.value() as u128,
+            reference_interleave(
+                &[
+                    x10.value() as u128,
+                    y10.value() as u128,
+                    z10.value() as u128
+                ],
+                10
+            )
+        );
+        assert_eq!(key.deinterleave_3d(), (x10, y10, z10));
+
+        value = value.rotate_left(7).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+
+    // Usable in const contexts
+    const KEY: u63 = u63::interleave_3d(u21::MAX, u21::new(0), u21::new(0));
+    const COORDINATES: (u21, u21, u21) = KEY.deinterleave_3d();
+    assert_eq!(KEY, u63::new(0x1249_2492_4924_9249));
+    assert_eq!(COORDINATES, (u21::MAX, u21::new(0), u21::new(0)));
+}